@@ -1,5 +1,5 @@
 import { Buffer } from "buffer";
-import { Address } from '@stellar/stellar-sdk';
+import { Address } from "@stellar/stellar-sdk";
 import {
   AssembledTransaction,
   Client as ContractClient,
@@ -7,7 +7,7 @@ import {
   MethodOptions,
   Result,
   Spec as ContractSpec,
-} from '@stellar/stellar-sdk/contract';
+} from "@stellar/stellar-sdk/contract";
 import type {
   u32,
   i32,
@@ -18,14 +18,14 @@ import type {
   u256,
   i256,
   Option,
-  Typepoint,
+  Timepoint,
   Duration,
-} from '@stellar/stellar-sdk/contract';
-export * from '@stellar/stellar-sdk'
-export * as contract from '@stellar/stellar-sdk/contract'
-export * as rpc from '@stellar/stellar-sdk/rpc'
+} from "@stellar/stellar-sdk/contract";
+export * from "@stellar/stellar-sdk";
+export * as contract from "@stellar/stellar-sdk/contract";
+export * as rpc from "@stellar/stellar-sdk/rpc";
 
-if (typeof window !== 'undefined') {
+if (typeof window !== "undefined") {
   //@ts-ignore Buffer exists
   window.Buffer = window.Buffer || Buffer;
 }
@@ -33,6 +33,180 @@ if (typeof window !== 'undefined') {
 
 
 
+
+export interface Bill {
+  contributors: Array<string>;
+  event_id: u64;
+  expires_at: u64;
+  funded: i128;
+  id: u64;
+  memo: Option<string>;
+  payee: string;
+  status: BillStatus;
+  total: i128;
+}
+
+export type Asset = {tag: "Stellar", values: readonly [string]} | {tag: "Other", values: readonly [string]};
+
+
+export interface Event {
+  created_at: u64;
+  fee_rate: u32;
+  id: u64;
+  is_active: boolean;
+  name: string;
+  organizer: string;
+  total_volume: i128;
+}
+
+
+export interface Escrow {
+  amount: i128;
+  buyer: string;
+  created_at: u64;
+  event_id: u64;
+  fee_amount: i128;
+  fee_mode: FeeMode;
+  fee_rate: u32;
+  id: u64;
+  memo: Option<string>;
+  release_at: u64;
+  seller: string;
+  status: EscrowStatus;
+}
+
+
+export interface Ticket {
+  event_id: u64;
+  face_value: i128;
+  id: u64;
+  owner: string;
+  resale_price: Option<i128>;
+}
+
+export type FeeMode = {tag: "Sender", values: void} | {tag: "Sponsor", values: void} | {tag: "Recipient", values: void};
+
+
+export interface Invoice {
+  amount: i128;
+  created_at: u64;
+  event_id: u64;
+  expires_at: u64;
+  id: u64;
+  memo: string;
+  payee: string;
+  payer: Option<string>;
+  status: InvoiceStatus;
+}
+
+
+
+export interface Solvency {
+  balance: i128;
+  liabilities: i128;
+  solvent: boolean;
+  surplus: i128;
+}
+
+
+export interface FeePolicy {
+  min_fee: i128;
+  rounding: RoundingMode;
+}
+
+
+export interface PriceData {
+  price: i128;
+  timestamp: u64;
+}
+
+
+export interface SwapRoute {
+  amount_in: i128;
+  min_out: i128;
+  send_token: string;
+  via: Array<string>;
+}
+
+
+export interface ArtistTips {
+  artist: string;
+  total: i128;
+}
+
+export type BillStatus = {tag: "Open", values: void} | {tag: "Settled", values: void} | {tag: "Cancelled", values: void};
+
+export type PaymentKey = {tag: "Ed25519", values: readonly [Buffer]} | {tag: "Secp256r1", values: readonly [Buffer]};
+
+
+
+
+
+export interface FiatPricing {
+  currency: string;
+  max_price_age: u64;
+  oracle: string;
+}
+
+
+export interface PayoutShare {
+  recipient: string;
+  share_bps: u32;
+}
+
+
+
+
+
+export interface DiscountCode {
+  event_id: u64;
+  expires_at: u64;
+  kind: DiscountKind;
+  max_uses: u32;
+  uses: u32;
+}
+
+export type DiscountKind = {tag: "TicketPercent", values: readonly [u32]} | {tag: "FeeWaiver", values: void};
+
+export type EscrowStatus = {tag: "Held", values: void} | {tag: "Disputed", values: void} | {tag: "Released", values: void} | {tag: "Refunded", values: void};
+
+
+export interface ResaleConfig {
+  max_markup_bps: u32;
+  royalty_bps: u32;
+  transferable: boolean;
+}
+
+export type RoundingMode = {tag: "Floor", values: void} | {tag: "Ceil", values: void} | {tag: "HalfEven", values: void};
+
+
+export interface SessionGrant {
+  amount_cap: i128;
+  delegate: string;
+  event_id: u64;
+  expires_at: u64;
+  grantor: string;
+  spent: i128;
+}
+
+
+export interface Subscription {
+  amount: i128;
+  event_id: u64;
+  id: u64;
+  is_active: boolean;
+  next_due: u64;
+  payee: string;
+  payer: string;
+  payments_collected: u32;
+  period: u64;
+}
+
+
+
+
+
+
 export const ContractError = {
   1: {message:"FeeRateExceeds10Percent"},
   2: {message:"AmountMustBePositive"},
@@ -48,472 +222,856 @@ export const ContractError = {
   12: {message:"EventStillActive"},
   13: {message:"WalletNotRegistered"},
   14: {message:"WalletAlreadyRegistered"},
-  15: {message:"OrganizerCannotRegister"}
+  15: {message:"OrganizerCannotRegister"},
+  16: {message:"SpendingLimitExceeded"},
+  17: {message:"SessionGrantExceeded"},
+  18: {message:"RecordNotFound"},
+  19: {message:"Expired"},
+  20: {message:"TextTooLong"},
+  21: {message:"CapacityReached"},
+  22: {message:"InvalidState"},
+  23: {message:"NotYetDue"},
+  24: {message:"InvalidAmount"},
+  25: {message:"Unauthorized"},
+  26: {message:"TicketNotTransferable"},
+  27: {message:"ResalePriceAboveCap"},
+  28: {message:"ContributionExceedsRemaining"},
+  29: {message:"ArtistNotRegistered"},
+  30: {message:"InsufficientPoints"},
+  31: {message:"LoyaltyRedemptionDisabled"},
+  32: {message:"InsufficientEventFees"},
+  33: {message:"DiscountCodeExhausted"},
+  34: {message:"DiscountNotApplicable"},
+  35: {message:"SweepExceedsSurplus"},
+  36: {message:"ArithmeticOverflow"},
+  37: {message:"OraclePriceStale"},
+  38: {message:"SlippageExceeded"},
+  39: {message:"InvalidSignature"},
+  40: {message:"NonceAlreadyUsed"}
 }
 
+export type InvoiceStatus = {tag: "Open", values: void} | {tag: "Paid", values: void} | {tag: "Expired", values: void} | {tag: "Cancelled", values: void};
 
-export interface Event {
-  created_at: u64;
+
+export interface LoyaltyConfig {
+  earn_rate_bps: u32;
+  point_value_bps: u32;
+}
+
+export type LoyaltyReward = {tag: "FeeDiscount", values: void} | {tag: "Voucher", values: void};
+
+
+export interface PaymentIntent {
+  amount: i128;
+  event_id: u64;
+  expires_at: u64;
+  nonce: u64;
+  payee: string;
+  payer: string;
+}
+
+
+export interface PaymentRecord {
+  amount: i128;
+  event_id: u64;
+  fee_amount: i128;
+  fee_payer: string;
   fee_rate: u32;
+  from: string;
   id: u64;
-  is_active: boolean;
-  name: string;
-  organizer: string;
-  total_volume: i128;
+  memo: Option<string>;
+  timestamp: u64;
+  to: string;
+}
+
+
+export interface SpendingLimit {
+  per_day: Option<i128>;
+  per_event: Option<i128>;
+  per_payment: Option<i128>;
 }
 
 
 
 
+
+
+
+
+
+
 export interface ContractConfig {
   admin: string;
   default_fee_rate: u32;
   next_event_id: u64;
   token_address: string;
 }
+
+
+export interface WalletSpending {
+  day: u64;
+  spent_today: i128;
+  spent_total: i128;
+}
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+export interface OrganizerTreasury {
+  accumulated: i128;
+  event_count: u32;
+  withdrawable: i128;
+  withdrawn: i128;
+}
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
 
 export interface Client {
   /**
-   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Inicializa o contrato com taxa padrão, admin e token
+   * Construct and simulate a tip transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Tip an artist; uses the event's tip fee rate instead of the regular one
+   */
+  tip: ({event_id, from, artist, amount}: {event_id: u64, from: string, artist: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * NFT collection name
+   */
+  name: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a sweep transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resgata tokens enviados por engano (apenas admin). Do token do contrato,
+   * só o que excede as obrigações registradas pode sair
+   */
+  sweep: ({token, to, amount}: {token: string, to: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * NFT collection symbol
+   */
+  symbol: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Number of tickets owned by an account, across all events
+   */
+  balance: ({account}: {account: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_bill transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query a split bill
+   */
+  get_bill: ({bill_id}: {bill_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Bill>>>
+
+  /**
+   * Construct and simulate a owner_of transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Owner of a ticket
+   */
+  owner_of: ({token_id}: {token_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Transfer a ticket, gated by the event's transferability rules
    */
-  initialize: ({admin, default_fee_rate, token_address}: {admin: string, default_fee_rate: u32, token_address: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  transfer: ({from, to, token_id}: {from: string, to: string, token_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query event information
+   */
+  get_event: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Event>>>
+
+  /**
+   * Construct and simulate a is_artist transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Verifica se um artista pode receber gorjetas no evento
+   */
+  is_artist: ({event_id, artist}: {event_id: u64, artist: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a open_bill transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Abre uma conta para ser dividida entre várias carteiras (apenas o recebedor);
+   * se não for financiada até `expires_at`, cada contribuinte pode recuperar sua parte
+   */
+  open_bill: ({event_id, payee, total, memo, expires_at}: {event_id: u64, payee: string, total: i128, memo: Option<string>, expires_at: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a token_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Metadata URI of a ticket: event base URI followed by the ticket id
+   */
+  token_uri: ({token_id}: {token_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a buy_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Compra atômica de um ingresso listado; o royalty do organizador vai para as taxas do evento
+   */
+  buy_ticket: ({ticket_id, buyer}: {ticket_id: u64, buyer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
    * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Consulta configuração do contrato (apenas admin)
+   * Query contract configuration (admin only)
    */
-  get_config: ({admin}: {admin: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_config: ({admin}: {admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<ContractConfig>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query an escrowed payment
+   */
+  get_escrow: ({escrow_id}: {escrow_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Escrow>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<ContractConfig>>>
+  /**
+   * Construct and simulate a get_points transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Consulta saldo de pontos de uma carteira no evento
+   */
+  get_points: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
   /**
-   * Construct and simulate a update_default_fee_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Atualiza taxa padrão (apenas admin)
+   * Construct and simulate a get_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query a ticket
+   */
+  get_ticket: ({ticket_id}: {ticket_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Ticket>>>
+
+  /**
+   * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Initialize contract with default fee rate, admin and token
    */
-  update_default_fee_rate: ({admin, new_fee_rate}: {admin: string, new_fee_rate: u32}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  initialize: ({admin, default_fee_rate, token_address}: {admin: string, default_fee_rate: u32, token_address: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a quote_fiat transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Token amount a fiat amount converts to right now, for choosing a slippage bound
+   */
+  quote_fiat: ({event_id, fiat_amount}: {event_id: u64, fiat_amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a cancel_bill transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancela uma conta ainda não financiada e devolve as contribuições (apenas o recebedor)
+   */
+  cancel_bill: ({bill_id}: {bill_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a collect_due transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Collect the payment due for the current period (anyone can call)
+   */
+  collect_due: ({subscription_id}: {subscription_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_invoice transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query an invoice (open invoices past their expiry are reported as expired)
+   */
+  get_invoice: ({invoice_id}: {invoice_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Invoice>>>
+
+  /**
+   * Construct and simulate a get_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query a payment record by id
+   */
+  get_payment: ({payment_id}: {payment_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<PaymentRecord>>>
+
+  /**
+   * Construct and simulate a list_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lista todos os eventos (limitado para evitar problemas de gas)
+   */
+  list_events: ({limit}: {limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<Event>>>>
+
+  /**
+   * Construct and simulate a list_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * List a ticket for resale, up to the organizer's price cap
+   */
+  list_ticket: ({ticket_id, price}: {ticket_id: u64, price: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a pay_invoice transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay an open invoice (same rules and fees as `event_payment`)
+   */
+  pay_invoice: ({invoice_id, payer}: {invoice_id: u64, payer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_payout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Saca a cota de uma parte da divisão
+   */
+  claim_payout: ({event_id, recipient}: {event_id: u64, recipient: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
   /**
    * Construct and simulate a create_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Cria um novo evento/festival
    */
-  create_event: ({organizer, name, fee_rate}: {organizer: string, name: string, fee_rate: Option<u32>}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  create_event: ({organizer, name, fee_rate}: {organizer: string, name: string, fee_rate: Option<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the fees of all events of an organizer
+   */
+  get_treasury: ({organizer}: {organizer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<OrganizerTreasury>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<u64>>>
+  /**
+   * Construct and simulate a issue_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Emite um ingresso para uma carteira, registrando-a no evento (apenas organizador)
+   */
+  issue_ticket: ({event_id, owner, face_value}: {event_id: u64, owner: string, face_value: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
-   * Construct and simulate a create_event_with_allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Cria um evento e autoriza automaticamente o contrato a gastar tokens do organizador para taxas
+   * Construct and simulate a path_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay with another token: `route.amount_in` of `route.send_token` is swapped into the event
+   * token through the router and the output, at least `route.min_out`, is paid to `to` like a
+   * regular event payment
    */
-  create_event_with_allowance: ({organizer, name, fee_rate, max_allowance}: {organizer: string, name: string, fee_rate: Option<u32>, max_allowance: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  path_payment: ({event_id, from, to, route, memo}: {event_id: u64, from: string, to: string, route: SwapRoute, memo: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a event_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Make payment for a specific event; the event's fee mode decides who pays the fee
+   */
+  event_payment: ({event_id, from, to, amount, memo}: {event_id: u64, from: string, to: string, amount: i128, memo: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a is_nonce_used transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether a payer's nonce was already submitted or cancelled
+   */
+  is_nonce_used: ({payer, nonce}: {payer: string, nonce: u64}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a redeem_points transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Redeem points at an event for a fee discount or a voucher; returns the token value
+   */
+  redeem_points: ({event_id, wallet, points, reward}: {event_id: u64, wallet: string, points: i128, reward: LoyaltyReward}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a cancel_invoice transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancela uma cobrança em aberto (apenas o vendedor)
+   */
+  cancel_invoice: ({invoice_id}: {invoice_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a check_solvency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Compare recorded liabilities with the contract's token balance
+   */
+  check_solvency: (options?: MethodOptions) => Promise<AssembledTransaction<Result<Solvency>>>
+
+  /**
+   * Construct and simulate a confirm_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Buyer confirms receipt and releases the funds to the seller
+   */
+  confirm_escrow: ({escrow_id}: {escrow_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a create_invoice transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cria uma cobrança que pode ser paga por id (apenas o vendedor)
+   */
+  create_invoice: ({event_id, payee, amount, memo, expires_at}: {event_id: u64, payee: string, amount: i128, memo: string, expires_at: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a dispute_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Buyer opens a dispute within the window, leaving the decision to the event organizer
+   */
+  dispute_escrow: ({escrow_id}: {escrow_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a escrow_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Paga para o contrato em custódia; o vendedor recebe após confirmação ou fim da janela de disputa
+   */
+  escrow_payment: ({event_id, buyer, seller, amount, dispute_window, memo}: {event_id: u64, buyer: string, seller: string, amount: i128, dispute_window: u64, memo: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a get_event_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Consulta taxas acumuladas de um evento
+   */
+  get_event_fees: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_fee_budget transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query what's left of the fee_payer's budget for payment_with_auth_fee_payer
+   */
+  get_fee_budget: ({fee_payer}: {fee_payer: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_fee_credit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the fee credit a wallet has left in an event
+   */
+  get_fee_credit: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_fee_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the fee policy (default: floor rounding, no minimum fee)
+   */
+  get_fee_policy: (options?: MethodOptions) => Promise<AssembledTransaction<FeePolicy>>
+
+  /**
+   * Construct and simulate a release_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Libera os fundos ao vendedor após o fim da janela de disputa (qualquer pessoa pode chamar)
+   */
+  release_escrow: ({escrow_id}: {escrow_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a set_fee_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define arredondamento e taxa mínima (apenas admin)
+   */
+  set_fee_policy: ({admin, policy}: {admin: string, policy: FeePolicy}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_artist_tips transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query total tips received by an artist in an event
+   */
+  get_artist_tips: ({event_id, artist}: {event_id: u64, artist: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_liabilities transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Total que o contrato deve: taxas de eventos, repasses não sacados, escrows e contas abertas
+   */
+  get_liabilities: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_payment_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the key a wallet signs payment intents with
+   */
+  get_payment_key: ({wallet}: {wallet: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<PaymentKey>>>
+
+  /**
+   * Construct and simulate a get_swap_router transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the DEX router used for path payments, if any
+   */
+  get_swap_router: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a purchase_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Buy a ticket from the organizer, optionally with a ticket discount code
+   */
+  purchase_ticket: ({event_id, buyer, code}: {event_id: u64, buyer: string, code: Option<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<u64>>>
+  /**
+   * Construct and simulate a register_artist transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Registra um artista para receber gorjetas no evento (apenas organizador)
+   */
+  register_artist: ({event_id, artist}: {event_id: u64, artist: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_payment_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Registra (ou remove com None) a chave que assina as intenções de pagamento da carteira
+   */
+  set_payment_key: ({wallet, key}: {wallet: string, key: Option<PaymentKey>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_swap_router transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define o roteador de DEX usado nos pagamentos com troca de token (apenas admin)
+   */
+  set_swap_router: ({admin, router}: {admin: string, router: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a transfer_ticket transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Transfere um ingresso para outra carteira sem venda (apenas dono)
+   */
+  transfer_ticket: ({ticket_id, from, to}: {ticket_id: u64, from: string, to: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_fiat_pricing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query an event's fiat pricing (None = priced in tokens)
+   */
+  get_fiat_pricing: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<FiatPricing>>>
+
+  /**
+   * Construct and simulate a get_payout_split transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the payout split of an event (empty = fees go to the organizer)
+   */
+  get_payout_split: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Array<PayoutShare>>>
+
+  /**
+   * Construct and simulate a get_subscription transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query a subscription
+   */
+  get_subscription: ({subscription_id}: {subscription_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Subscription>>>
+
+  /**
+   * Construct and simulate a get_ticket_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the primary sale price of tickets, if they are on sale
+   */
+  get_ticket_price: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Option<i128>>>
+
+  /**
+   * Construct and simulate a get_tip_fee_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the fee rate charged on tips
+   */
+  get_tip_fee_rate: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
   /**
    * Construct and simulate a set_event_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Ativa ou desativa um evento (apenas organizador)
    */
-  set_event_status: ({event_id, is_active}: {event_id: u64, is_active: boolean}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_event_status: ({event_id, is_active}: {event_id: u64, is_active: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a set_fiat_pricing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Denomina os preços do evento em moeda fiduciária, ou volta para tokens com None (apenas organizador)
+   */
+  set_fiat_pricing: ({event_id, pricing}: {event_id: u64, pricing: Option<FiatPricing>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a set_payout_split transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define como as taxas do evento são divididas entre parceiros (apenas organizador).
+   * As cotas devem somar 10000 basis points; uma lista vazia remove a divisão
+   */
+  set_payout_split: ({event_id, shares}: {event_id: u64, shares: Array<PayoutShare>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Consulta informações de um evento
+   * Construct and simulate a set_ticket_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define o preço de venda de ingressos pelo contrato (apenas organizador);
+   * em moeda fiduciária quando o evento tem preço em fiat
    */
-  get_event: ({event_id}: {event_id: u64}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_ticket_price: ({event_id, price}: {event_id: u64, price: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a set_tip_fee_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the fee rate charged on tips (organizer only, default 0)
+   */
+  set_tip_fee_rate: ({event_id, fee_rate}: {event_id: u64, fee_rate: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<Event>>>
+  /**
+   * Construct and simulate a get_discount_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query a discount code by its hash
+   */
+  get_discount_code: ({event_id, code_hash}: {event_id: u64, code_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<DiscountCode>>>
 
   /**
    * Construct and simulate a get_event_by_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Consulta evento por nome
    */
-  get_event_by_name: ({name}: {name: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_event_by_name: ({name}: {name: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Event>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_resale_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query resale rules (default: transferable, no markup, no royalty)
+   */
+  get_resale_config: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<ResaleConfig>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<Event>>>
+  /**
+   * Construct and simulate a get_session_grant transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query a session grant
+   */
+  get_session_grant: ({grantor, delegate, event_id}: {grantor: string, delegate: string, event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<SessionGrant>>>
 
   /**
-   * Construct and simulate a list_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Lista todos os eventos (limitado para evitar problemas de gas)
+   * Construct and simulate a set_resale_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define as regras de revenda do evento (apenas organizador)
    */
-  list_events: ({limit}: {limit: u32}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_resale_config: ({event_id, config}: {event_id: u64, config: ResaleConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a withdraw_all_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Saca de uma vez as taxas de todos os eventos do organizador:
+   * tudo dos eventos inativos e até o limite configurado dos ativos
+   */
+  withdraw_all_fees: ({organizer}: {organizer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<Array<Event>>>>
+  /**
+   * Construct and simulate a contribute_to_bill transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Contribute a share to an open bill; the contribution that completes it settles the bill
+   */
+  contribute_to_bill: ({bill_id, contributor, amount}: {bill_id: u64, contributor: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_event_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Consulta taxas acumuladas de um evento
+   * Construct and simulate a event_payment_fiat transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Event payment of a fiat amount, converted at the oracle price; fails if it costs more than `max_amount` tokens
    */
-  get_event_fees: ({event_id}: {event_id: u64}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  event_payment_fiat: ({event_id, from, to, fiat_amount, max_amount, memo}: {event_id: u64, from: string, to: string, fiat_amount: i128, max_amount: i128, memo: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_event_fee_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query who pays the fee on event payments (default: recipient)
+   */
+  get_event_fee_mode: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<FeeMode>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  /**
+   * Construct and simulate a get_loyalty_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the loyalty settings of an event (default: no points)
+   */
+  get_loyalty_config: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<LoyaltyConfig>>
 
   /**
-   * Construct and simulate a get_fee_authorization transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Consulta o allowance do fee_payer para o contrato
+   * Construct and simulate a get_spending_limit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the effective caps of a wallet (the tightest of organizer and self-set limits)
    */
-  get_fee_authorization: ({fee_payer}: {fee_payer: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_spending_limit: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: MethodOptions) => Promise<AssembledTransaction<SpendingLimit>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a reclaim_bill_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Devolve a parte de um contribuinte de uma conta que expirou sem ser financiada
+   */
+  reclaim_bill_share: ({bill_id, contributor}: {bill_id: u64, contributor: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  /**
+   * Construct and simulate a set_event_fee_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define quem paga a taxa nos pagamentos do evento (apenas organizador)
+   */
+  set_event_fee_mode: ({event_id, mode}: {event_id: u64, mode: FeeMode}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a register_wallet_for_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Registra uma carteira para participar de um evento
+   * Construct and simulate a set_loyalty_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Configura o programa de pontos do evento (apenas organizador)
    */
-  register_wallet_for_event: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_loyalty_config: ({event_id, config}: {event_id: u64, config: LoyaltyConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a cancel_subscription transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a subscription (payer only)
+   */
+  cancel_subscription: ({subscription_id}: {subscription_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a create_subscription transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cria uma assinatura (ex.: passe de temporada); a primeira cobrança vence imediatamente
+   * Note: increases the payer's token allowance to the contract by `allowance`
+   */
+  create_subscription: ({event_id, payer, payee, amount, period, allowance}: {event_id: u64, payer: string, payee: string, amount: i128, period: u64, allowance: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
-   * Construct and simulate a unregister_wallet_from_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Remove registro de uma carteira de um evento
+   * Construct and simulate a get_tip_leaderboard transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Artistas do evento ordenados pelo total de gorjetas (maior primeiro)
    */
-  unregister_wallet_from_event: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_tip_leaderboard: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Array<ArtistTips>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_wallet_spending transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query how much a wallet has spent within an event (spent_today is reset on a new day)
+   */
+  get_wallet_spending: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: MethodOptions) => Promise<AssembledTransaction<WalletSpending>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a set_ticket_base_uri transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define a URI base dos metadados dos ingressos do evento (apenas organizador)
+   */
+  set_ticket_base_uri: ({event_id, base_uri}: {event_id: u64, base_uri: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a is_wallet_registered transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Verifica se uma carteira está registrada para um evento
+   * Construct and simulate a withdraw_event_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Permite ao organizador sacar taxas acumuladas (apenas se evento estiver inativo)
    */
-  is_wallet_registered: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  withdraw_event_fees: ({event_id}: {event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a cancel_payment_nonce transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Invalidate a nonce so an intent signed with it can no longer be submitted
+   */
+  cancel_payment_nonce: ({payer, nonce}: {payer: string, nonce: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  /**
+   * Construct and simulate a create_discount_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cria um código promocional a partir do hash SHA-256 do código (apenas organizador)
+   */
+  create_discount_code: ({event_id, code_hash, kind, max_uses, expires_at}: {event_id: u64, code_hash: Buffer, kind: DiscountKind, max_uses: u32, expires_at: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a event_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Realiza pagamento para um evento específico usando fundos da carteira
+   * Construct and simulate a create_session_grant transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Autoriza uma chave delegada a pagar em nome da carteira dentro de um evento
+   * Note: raises the grantor's token allowance to the contract by `amount_cap`; re-creating
+   * a grant replaces the previous one's unspent allowance. The cap itself is enforced from
+   * the grant's `spent`, not from the allowance other features share
    */
-  event_payment: ({event_id, from, to, amount}: {event_id: u64, from: string, to: string, amount: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  create_session_grant: ({grantor, delegate, event_id, amount_cap, expires_at}: {grantor: string, delegate: string, event_id: u64, amount_cap: i128, expires_at: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_claimable_payout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the fees a split party can pull from an event
+   */
+  get_claimable_payout: ({event_id, recipient}: {event_id: u64, recipient: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a get_organizer_events transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lista os eventos criados por um organizador
+   */
+  get_organizer_events: ({organizer}: {organizer: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u64>>>
 
   /**
-   * Construct and simulate a payment_with_third_party_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Realiza pagamento geral (sem evento específico) - mantém compatibilidade
+   * Construct and simulate a is_wallet_registered transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if a wallet is registered for an event
    */
-  payment_with_third_party_fee: ({from, to, fee_payer, amount}: {from: string, to: string, fee_payer: string, amount: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  is_wallet_registered: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a revoke_session_grant transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a session grant (grantor only); its unspent allowance is withdrawn
+   */
+  revoke_session_grant: ({grantor, delegate, event_id}: {grantor: string, delegate: string, event_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a cancel_ticket_listing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Remove a ticket from resale (owner only)
+   */
+  cancel_ticket_listing: ({ticket_id}: {ticket_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a payment_with_auth_fee_payer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Realiza pagamento com fee_payer pré-autorizado (sem assinatura)
+   * Construct and simulate a get_bill_contribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query how much a wallet contributed to a bill
    */
-  payment_with_auth_fee_payer: ({from, to, fee_payer, amount}: {from: string, to: string, fee_payer: string, amount: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  get_bill_contribution: ({bill_id, contributor}: {bill_id: u64, contributor: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a get_fee_authorization transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Consulta o allowance do fee_payer para o contrato
+   */
+  get_fee_authorization: ({fee_payer}: {fee_payer: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a submit_signed_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a payment intent signed off-chain by the payer. Anyone can submit it; the amount
+   * is drawn from the allowance the payer gave the contract
+   */
+  submit_signed_payment: ({intent, signature}: {intent: PaymentIntent, signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
    * Construct and simulate a authorize_fee_payments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Autoriza o contrato a usar tokens do usuário para pagar fees
-   * Nota: Esta função chama approve() no contrato de token
+   * Authorize contract to use user's tokens to pay fees of payment_with_auth_fee_payer.
+   * Sets the fee budget (replacing the previous one) and moves the token allowance by the difference
+   */
+  authorize_fee_payments: ({fee_payer, max_fee_amount}: {fee_payer: string, max_fee_amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a resolve_escrow_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Organizador arbitra a disputa: reembolsa o comprador (sem taxa) ou paga o vendedor;
+   * quem adiantou a taxa (comprador ou organizador) a recebe de volta
+   */
+  resolve_escrow_dispute: ({escrow_id, refund_buyer}: {escrow_id: u64, refund_buyer: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a signed_payment_payload transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bytes the payer signs for an intent: the XDR of (this contract, intent)
    */
-  authorize_fee_payments: ({fee_payer, max_fee_amount}: {fee_payer: string, max_fee_amount: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  signed_payment_payload: ({intent}: {intent: PaymentIntent}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a submit_passkey_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a payment intent signed with a passkey (WebAuthn assertion from `navigator.credentials.get`).
+   * The challenge is the SHA-256 of the payload, base64url-encoded in clientDataJSON
+   */
+  submit_passkey_payment: ({intent, authenticator_data, client_data_json, signature}: {intent: PaymentIntent, authenticator_data: Buffer, client_data_json: Buffer, signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a delegated_event_payment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pagamento feito pela chave delegada, debitando a carteira que concedeu a sessão
+   */
+  delegated_event_payment: ({event_id, grantor, delegate, to, amount, memo}: {event_id: u64, grantor: string, delegate: string, to: string, amount: i128, memo: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
-   * Construct and simulate a revoke_fee_authorization transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Remove autorização para pagamento automático de fees
+   * Construct and simulate a event_payment_with_code transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Event payment with a fee waiver code: the recipient receives the full amount
    */
-  revoke_fee_authorization: ({fee_payer}: {fee_payer: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  event_payment_with_code: ({event_id, from, to, amount, memo, code}: {event_id: u64, from: string, to: string, amount: i128, memo: Option<string>, code: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a set_self_spending_limit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows a wallet to set its own caps within an event
+   */
+  set_self_spending_limit: ({event_id, wallet, limit}: {event_id: u64, wallet: string, limit: SpendingLimit}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  /**
+   * Construct and simulate a update_default_fee_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update default fee rate (admin only)
+   */
+  update_default_fee_rate: ({admin, new_fee_rate}: {admin: string, new_fee_rate: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a increase_event_allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Permite ao organizador aumentar o allowance para cobrir mais taxas do evento
    */
-  increase_event_allowance: ({event_id, additional_allowance}: {event_id: u64, additional_allowance: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  increase_event_allowance: ({event_id, additional_allowance}: {event_id: u64, additional_allowance: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a revoke_fee_authorization transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Remove authorization for automatic fee payment, withdrawing the unspent budget from the allowance
+   */
+  revoke_fee_authorization: ({fee_payer}: {fee_payer: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a set_event_spending_limit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define os limites padrão de gasto para todas as carteiras do evento (apenas organizador)
+   */
+  set_event_spending_limit: ({event_id, limit}: {event_id: u64, limit: SpendingLimit}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<void>>>
+  /**
+   * Construct and simulate a register_wallet_for_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Registra uma carteira para participar de um evento (organizador paga taxa)
+   */
+  register_wallet_for_event: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a withdraw_event_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Permite ao organizador sacar taxas acumuladas (apenas se evento estiver inativo)
+   * Construct and simulate a set_wallet_spending_limit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define limites de gasto para uma carteira específica do evento (apenas organizador)
    */
-  withdraw_event_fees: ({event_id}: {event_id: u64}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+  set_wallet_spending_limit: ({event_id, wallet, limit}: {event_id: u64, wallet: string, limit: SpendingLimit}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+  /**
+   * Construct and simulate a create_event_with_allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cria um evento e autoriza automaticamente o contrato a gastar tokens do organizador para taxas
+   */
+  create_event_with_allowance: ({organizer, name, fee_rate, max_allowance}: {organizer: string, name: string, fee_rate: Option<u32>, max_allowance: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<i128>>>
+  /**
+   * Construct and simulate a get_active_withdrawal_limit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Query the fraction of active-event fees that can be withdrawn (default 0)
+   */
+  get_active_withdrawal_limit: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a payment_with_auth_fee_payer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Make payment with pre-authorized fee_payer (no signature).
+   * A third-party fee_payer's fee is charged to the budget set with authorize_fee_payments
+   */
+  payment_with_auth_fee_payer: ({from, to, fee_payer, amount, memo}: {from: string, to: string, fee_payer: string, amount: i128, memo: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a set_active_withdrawal_limit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Fração (em basis points) das taxas de eventos ativos que pode ser sacada (apenas admin)
+   */
+  set_active_withdrawal_limit: ({admin, limit_bps}: {admin: string, limit_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a payment_with_third_party_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Make general payment (without specific event) - maintains compatibility.
+   * The fee_payer signs; the fee mode follows from who it is (see process_general_payment)
+   */
+  payment_with_third_party_fee: ({from, to, fee_payer, amount, memo}: {from: string, to: string, fee_payer: string, amount: i128, memo: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+
+  /**
+   * Construct and simulate a unregister_wallet_from_event transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Remove registro de uma carteira de um evento (organizador paga taxa)
+   */
+  unregister_wallet_from_event: ({event_id, wallet}: {event_id: u64, wallet: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a purchase_ticket_with_max_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Buy a ticket paying at most `max_price` tokens (slippage bound for fiat-priced events)
+   */
+  purchase_ticket_with_max_price: ({event_id, buyer, code, max_price}: {event_id: u64, buyer: string, code: Option<Buffer>, max_price: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
 }
 export class Client extends ContractClient {
@@ -533,56 +1091,331 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAABAAAAAAAAAAAAAAADUNvbnRyYWN0RXJyb3IAAAAAAAAPAAAAAAAAABdGZWVSYXRlRXhjZWVkczEwUGVyY2VudAAAAAABAAAAAAAAABRBbW91bnRNdXN0QmVQb3NpdGl2ZQAAAAIAAAAAAAAAFkNvbnRyYWN0Tm90SW5pdGlhbGl6ZWQAAAAAAAMAAAAAAAAAHUluc3VmZmljaWVudEJhbGFuY2VGcm9tU2VuZGVyAAAAAAAABAAAAAAAAAAVSW5zdWZmaWNpZW50QWxsb3dhbmNlAAAAAAAABQAAAAAAAAANRXZlbnROb3RGb3VuZAAAAAAAAAYAAAAAAAAADkV2ZW50Tm90QWN0aXZlAAAAAAAHAAAAAAAAABFOb3RFdmVudE9yZ2FuaXplcgAAAAAAAAgAAAAAAAAAEEV2ZW50TmFtZVRvb0xvbmcAAAAJAAAAAAAAABJFdmVudEFscmVhZHlFeGlzdHMAAAAAAAoAAAAAAAAAEkFscmVhZHlJbml0aWFsaXplZAAAAAAACwAAAAAAAAAQRXZlbnRTdGlsbEFjdGl2ZQAAAAwAAAAAAAAAE1dhbGxldE5vdFJlZ2lzdGVyZWQAAAAADQAAAAAAAAAXV2FsbGV0QWxyZWFkeVJlZ2lzdGVyZWQAAAAADgAAAAAAAAAXT3JnYW5pemVyQ2Fubm90UmVnaXN0ZXIAAAAADw==",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEJpbGwAAAAJAAAAAAAAAAxjb250cmlidXRvcnMAAAPqAAAAEwAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAAZmdW5kZWQAAAAAAAsAAAAAAAAAAmlkAAAAAAAGAAAAAAAAAARtZW1vAAAD6AAAABAAAAAAAAAABXBheWVlAAAAAAAAEwAAAAAAAAAGc3RhdHVzAAAAAAfQAAAACkJpbGxTdGF0dXMAAAAAAAAAAAAFdG90YWwAAAAAAAAL",
+        "AAAAAgAAAAAAAAAAAAAABUFzc2V0AAAAAAAAAgAAAAEAAAAAAAAAB1N0ZWxsYXIAAAAAAQAAABMAAAABAAAAAAAAAAVPdGhlcgAAAAAAAAEAAAAR",
         "AAAAAQAAAAAAAAAAAAAABUV2ZW50AAAAAAAABwAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAIZmVlX3JhdGUAAAAEAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAJaXNfYWN0aXZlAAAAAAAAAQAAAAAAAAAEbmFtZQAAABAAAAAAAAAACW9yZ2FuaXplcgAAAAAAABMAAAAAAAAADHRvdGFsX3ZvbHVtZQAAAAs=",
-        "AAAABQAAAAAAAAAAAAAADEV2ZW50Q3JlYXRlZAAAAAEAAAANZXZlbnRfY3JlYXRlZAAAAAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAAAAAAJb3JnYW5pemVyAAAAAAAAEwAAAAAAAAAAAAAACGZlZV9yYXRlAAAABAAAAAAAAAAC",
-        "AAAABQAAAAAAAAAAAAAADFBheW1lbnRFdmVudAAAAAEAAAANcGF5bWVudF9ldmVudAAAAAAAAAcAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAAAAAABGZyb20AAAATAAAAAAAAAAAAAAACdG8AAAAAABMAAAAAAAAAAAAAAAlmZWVfcGF5ZXIAAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAKZmVlX2Ftb3VudAAAAAAACwAAAAAAAAAAAAAACGZlZV9yYXRlAAAABAAAAAAAAAAC",
+        "AAAAAQAAAAAAAAAAAAAABkVzY3JvdwAAAAAADAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAKZmVlX2Ftb3VudAAAAAAACwAAAAAAAAAIZmVlX21vZGUAAAfQAAAAB0ZlZU1vZGUAAAAAAAAAAAhmZWVfcmF0ZQAAAAQAAAAAAAAAAmlkAAAAAAAGAAAAAAAAAARtZW1vAAAD6AAAABAAAAAAAAAACnJlbGVhc2VfYXQAAAAAAAYAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAGc3RhdHVzAAAAAAfQAAAADEVzY3Jvd1N0YXR1cw==",
+        "AAAAAQAAAAAAAAAAAAAABlRpY2tldAAAAAAABQAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAApmYWNlX3ZhbHVlAAAAAAALAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAxyZXNhbGVfcHJpY2UAAAPoAAAACw==",
+        "AAAAAgAAAAAAAAAAAAAAB0ZlZU1vZGUAAAAAAwAAAAAAAAAAAAAABlNlbmRlcgAAAAAAAAAAAAAAAAAHU3BvbnNvcgAAAAAAAAAAAAAAAAlSZWNpcGllbnQAAAA=",
+        "AAAAAQAAAAAAAAAAAAAAB0ludm9pY2UAAAAACQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAApjcmVhdGVkX2F0AAAAAAAGAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAAAmlkAAAAAAAGAAAAAAAAAARtZW1vAAAAEAAAAAAAAAAFcGF5ZWUAAAAAAAATAAAAAAAAAAVwYXllcgAAAAAAA+gAAAATAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAANSW52b2ljZVN0YXR1cwAAAA==",
+        "AAAABQAAAAAAAAAAAAAAB1RpcFNlbnQAAAAAAQAAAAh0aXBfc2VudAAAAAYAAAAAAAAACnBheW1lbnRfaWQAAAAAAAYAAAAAAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAAAAAABmFydGlzdAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAADGFydGlzdF90b3RhbAAAAAsAAAAAAAAAAg==",
+        "AAAAAQAAAAAAAAAAAAAACFNvbHZlbmN5AAAABAAAAAAAAAAHYmFsYW5jZQAAAAALAAAAAAAAAAtsaWFiaWxpdGllcwAAAAALAAAAAAAAAAdzb2x2ZW50AAAAAAEAAAAAAAAAB3N1cnBsdXMAAAAACw==",
+        "AAAAAQAAAAAAAAAAAAAACUZlZVBvbGljeQAAAAAAAAIAAAAAAAAAB21pbl9mZWUAAAAACwAAAAAAAAAIcm91bmRpbmcAAAfQAAAADFJvdW5kaW5nTW9kZQ==",
+        "AAAAAQAAAAAAAAAAAAAACVByaWNlRGF0YQAAAAAAAAIAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAACVN3YXBSb3V0ZQAAAAAAAAQAAAAAAAAACWFtb3VudF9pbgAAAAAAAAsAAAAAAAAAB21pbl9vdXQAAAAACwAAAAAAAAAKc2VuZF90b2tlbgAAAAAAEwAAAAAAAAADdmlhAAAAA+oAAAAT",
+        "AAAAAQAAAAAAAAAAAAAACkFydGlzdFRpcHMAAAAAAAIAAAAAAAAABmFydGlzdAAAAAAAEwAAAAAAAAAFdG90YWwAAAAAAAAL",
+        "AAAAAgAAAAAAAAAAAAAACkJpbGxTdGF0dXMAAAAAAAMAAAAAAAAAAAAAAARPcGVuAAAAAAAAAAAAAAAHU2V0dGxlZAAAAAAAAAAAAAAAAAlDYW5jZWxsZWQAAAA=",
+        "AAAAAgAAAAAAAAAAAAAAClBheW1lbnRLZXkAAAAAAAIAAAABAAAAAAAAAAdFZDI1NTE5AAAAAAEAAAPuAAAAIAAAAAEAAAAAAAAACVNlY3AyNTZyMQAAAAAAAAEAAAPuAAAAQQ==",
+        "AAAABQAAAAAAAAAAAAAACkJpbGxPcGVuZWQAAAAAAAEAAAALYmlsbF9vcGVuZWQAAAAABAAAAAAAAAAHYmlsbF9pZAAAAAAGAAAAAAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAFcGF5ZWUAAAAAAAATAAAAAAAAAAAAAAAFdG90YWwAAAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAACkZlZU1vZGVTZXQAAAAAAAEAAAAMZmVlX21vZGVfc2V0AAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAEbW9kZQAAB9AAAAAHRmVlTW9kZQAAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAClRpY2tldFNvbGQAAAAAAAEAAAALdGlja2V0X3NvbGQAAAAABgAAAAAAAAAJdGlja2V0X2lkAAAAAAAABgAAAAAAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAAAAAABnNlbGxlcgAAAAAAEwAAAAAAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAAAAAABXByaWNlAAAAAAAACwAAAAAAAAAAAAAAB3JveWFsdHkAAAAACwAAAAAAAAAC",
+        "AAAAAQAAAAAAAAAAAAAAC0ZpYXRQcmljaW5nAAAAAAMAAAAAAAAACGN1cnJlbmN5AAAAEQAAAAAAAAANbWF4X3ByaWNlX2FnZQAAAAAAAAYAAAAAAAAABm9yYWNsZQAAAAAAEw==",
+        "AAAAAQAAAAAAAAAAAAAAC1BheW91dFNoYXJlAAAAAAIAAAAAAAAACXJlY2lwaWVudAAAAAAAABMAAAAAAAAACXNoYXJlX2JwcwAAAAAAAAQ=",
+        "AAAABQAAAAAAAAAAAAAAC0JpbGxTZXR0bGVkAAAAAAEAAAAMYmlsbF9zZXR0bGVkAAAAAwAAAAAAAAAHYmlsbF9pZAAAAAAGAAAAAAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAKcGF5bWVudF9pZAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAC0ludm9pY2VQYWlkAAAAAAEAAAAMaW52b2ljZV9wYWlkAAAABQAAAAAAAAAKaW52b2ljZV9pZAAAAAAABgAAAAAAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAAAAAABXBheWVyAAAAAAAAEwAAAAAAAAAAAAAABXBheWVlAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAC1Rva2Vuc1N3ZXB0AAAAAAEAAAAMdG9rZW5zX3N3ZXB0AAAABAAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAAAAAACdG8AAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAAAQAAAAAAAAAAAAAADERpc2NvdW50Q29kZQAAAAUAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAAEa2luZAAAB9AAAAAMRGlzY291bnRLaW5kAAAAAAAAAAhtYXhfdXNlcwAAAAQAAAAAAAAABHVzZXMAAAAE",
+        "AAAAAgAAAAAAAAAAAAAADERpc2NvdW50S2luZAAAAAIAAAABAAAAAAAAAA1UaWNrZXRQZXJjZW50AAAAAAAAAQAAAAQAAAAAAAAAAAAAAAlGZWVXYWl2ZXIAAAA=",
+        "AAAAAgAAAAAAAAAAAAAADEVzY3Jvd1N0YXR1cwAAAAQAAAAAAAAAAAAAAARIZWxkAAAAAAAAAAAAAAAIRGlzcHV0ZWQAAAAAAAAAAAAAAAhSZWxlYXNlZAAAAAAAAAAAAAAACFJlZnVuZGVk",
+        "AAAAAQAAAAAAAAAAAAAADFJlc2FsZUNvbmZpZwAAAAMAAAAAAAAADm1heF9tYXJrdXBfYnBzAAAAAAAEAAAAAAAAAAtyb3lhbHR5X2JwcwAAAAAEAAAAAAAAAAx0cmFuc2ZlcmFibGUAAAAB",
+        "AAAAAgAAAAAAAAAAAAAADFJvdW5kaW5nTW9kZQAAAAMAAAAAAAAAAAAAAAVGbG9vcgAAAAAAAAAAAAAAAAAABENlaWwAAAAAAAAAAAAAAAhIYWxmRXZlbg==",
+        "AAAAAQAAAAAAAAAAAAAADFNlc3Npb25HcmFudAAAAAYAAAAAAAAACmFtb3VudF9jYXAAAAAAAAsAAAAAAAAACGRlbGVnYXRlAAAAEwAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAAdncmFudG9yAAAAABMAAAAAAAAABXNwZW50AAAAAAAACw==",
+        "AAAAAQAAAAAAAAAAAAAADFN1YnNjcmlwdGlvbgAAAAkAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAJaXNfYWN0aXZlAAAAAAAAAQAAAAAAAAAIbmV4dF9kdWUAAAAGAAAAAAAAAAVwYXllZQAAAAAAABMAAAAAAAAABXBheWVyAAAAAAAAEwAAAAAAAAAScGF5bWVudHNfY29sbGVjdGVkAAAAAAAEAAAAAAAAAAZwZXJpb2QAAAAAAAY=",
+        "AAAABQAAAAAAAAAAAAAADEV2ZW50Q3JlYXRlZAAAAAEAAAANZXZlbnRfY3JlYXRlZAAAAAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAAAAAAJb3JnYW5pemVyAAAAAAAAEwAAAAEAAAAAAAAACGZlZV9yYXRlAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADFBheW1lbnRFdmVudAAAAAEAAAANcGF5bWVudF9ldmVudAAAAAAAAAkAAAAAAAAACnBheW1lbnRfaWQAAAAAAAYAAAAAAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAARmcm9tAAAAEwAAAAEAAAAAAAAAAnRvAAAAAAATAAAAAQAAAAAAAAAJZmVlX3BheWVyAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAACmZlZV9hbW91bnQAAAAAAAsAAAAAAAAAAAAAAAhmZWVfcmF0ZQAAAAQAAAAAAAAAAAAAAARtZW1vAAAD6AAAABAAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADFBvaW50c0Vhcm5lZAAAAAEAAAANcG9pbnRzX2Vhcm5lZAAAAAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAAAAAABndhbGxldAAAAAAAEwAAAAAAAAAAAAAABnBvaW50cwAAAAAACwAAAAAAAAAAAAAAB2JhbGFuY2UAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADFRpY2tldElzc3VlZAAAAAEAAAANdGlja2V0X2lzc3VlZAAAAAAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAYAAAAAAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAAAAAAApmYWNlX3ZhbHVlAAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADFRpY2tldExpc3RlZAAAAAEAAAANdGlja2V0X2xpc3RlZAAAAAAAAAMAAAAAAAAACXRpY2tldF9pZAAAAAAAAAYAAAAAAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAVwcmljZQAAAAAAA+gAAAALAAAAAAAAAAI=",
+        "AAAABAAAAAAAAAAAAAAADUNvbnRyYWN0RXJyb3IAAAAAAAAoAAAAAAAAABdGZWVSYXRlRXhjZWVkczEwUGVyY2VudAAAAAABAAAAAAAAABRBbW91bnRNdXN0QmVQb3NpdGl2ZQAAAAIAAAAAAAAAFkNvbnRyYWN0Tm90SW5pdGlhbGl6ZWQAAAAAAAMAAAAAAAAAHUluc3VmZmljaWVudEJhbGFuY2VGcm9tU2VuZGVyAAAAAAAABAAAAAAAAAAVSW5zdWZmaWNpZW50QWxsb3dhbmNlAAAAAAAABQAAAAAAAAANRXZlbnROb3RGb3VuZAAAAAAAAAYAAAAAAAAADkV2ZW50Tm90QWN0aXZlAAAAAAAHAAAAAAAAABFOb3RFdmVudE9yZ2FuaXplcgAAAAAAAAgAAAAAAAAAEEV2ZW50TmFtZVRvb0xvbmcAAAAJAAAAAAAAABJFdmVudEFscmVhZHlFeGlzdHMAAAAAAAoAAAAAAAAAEkFscmVhZHlJbml0aWFsaXplZAAAAAAACwAAAAAAAAAQRXZlbnRTdGlsbEFjdGl2ZQAAAAwAAAAAAAAAE1dhbGxldE5vdFJlZ2lzdGVyZWQAAAAADQAAAAAAAAAXV2FsbGV0QWxyZWFkeVJlZ2lzdGVyZWQAAAAADgAAAAAAAAAXT3JnYW5pemVyQ2Fubm90UmVnaXN0ZXIAAAAADwAAAAAAAAAVU3BlbmRpbmdMaW1pdEV4Y2VlZGVkAAAAAAAAEAAAAAAAAAAUU2Vzc2lvbkdyYW50RXhjZWVkZWQAAAARAAAAAAAAAA5SZWNvcmROb3RGb3VuZAAAAAAAEgAAAAAAAAAHRXhwaXJlZAAAAAATAAAAAAAAAAtUZXh0VG9vTG9uZwAAAAAUAAAAAAAAAA9DYXBhY2l0eVJlYWNoZWQAAAAAFQAAAAAAAAAMSW52YWxpZFN0YXRlAAAAFgAAAAAAAAAJTm90WWV0RHVlAAAAAAAAFwAAAAAAAAANSW52YWxpZEFtb3VudAAAAAAAABgAAAAAAAAADFVuYXV0aG9yaXplZAAAABkAAAAAAAAAFVRpY2tldE5vdFRyYW5zZmVyYWJsZQAAAAAAABoAAAAAAAAAE1Jlc2FsZVByaWNlQWJvdmVDYXAAAAAAGwAAAAAAAAAcQ29udHJpYnV0aW9uRXhjZWVkc1JlbWFpbmluZwAAABwAAAAAAAAAE0FydGlzdE5vdFJlZ2lzdGVyZWQAAAAAHQAAAAAAAAASSW5zdWZmaWNpZW50UG9pbnRzAAAAAAAeAAAAAAAAABlMb3lhbHR5UmVkZW1wdGlvbkRpc2FibGVkAAAAAAAAHwAAAAAAAAAVSW5zdWZmaWNpZW50RXZlbnRGZWVzAAAAAAAAIAAAAAAAAAAVRGlzY291bnRDb2RlRXhoYXVzdGVkAAAAAAAAIQAAAAAAAAAVRGlzY291bnROb3RBcHBsaWNhYmxlAAAAAAAAIgAAAAAAAAATU3dlZXBFeGNlZWRzU3VycGx1cwAAAAAjAAAAAAAAABJBcml0aG1ldGljT3ZlcmZsb3cAAAAAACQAAAAAAAAAEE9yYWNsZVByaWNlU3RhbGUAAAAlAAAAAAAAABBTbGlwcGFnZUV4Y2VlZGVkAAAAJgAAAAAAAAAQSW52YWxpZFNpZ25hdHVyZQAAACcAAAAAAAAAEE5vbmNlQWxyZWFkeVVzZWQAAAAo",
+        "AAAAAgAAAAAAAAAAAAAADUludm9pY2VTdGF0dXMAAAAAAAAEAAAAAAAAAAAAAAAET3BlbgAAAAAAAAAAAAAABFBhaWQAAAAAAAAAAAAAAAdFeHBpcmVkAAAAAAAAAAAAAAAACUNhbmNlbGxlZAAAAA==",
+        "AAAAAQAAAAAAAAAAAAAADUxveWFsdHlDb25maWcAAAAAAAACAAAAAAAAAA1lYXJuX3JhdGVfYnBzAAAAAAAABAAAAAAAAAAPcG9pbnRfdmFsdWVfYnBzAAAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAADUxveWFsdHlSZXdhcmQAAAAAAAACAAAAAAAAAAAAAAALRmVlRGlzY291bnQAAAAAAAAAAAAAAAAHVm91Y2hlcgA=",
+        "AAAAAQAAAAAAAAAAAAAADVBheW1lbnRJbnRlbnQAAAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAAAVwYXllZQAAAAAAABMAAAAAAAAABXBheWVyAAAAAAAAEw==",
+        "AAAAAQAAAAAAAAAAAAAADVBheW1lbnRSZWNvcmQAAAAAAAAKAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAKZmVlX2Ftb3VudAAAAAAACwAAAAAAAAAJZmVlX3BheWVyAAAAAAAAEwAAAAAAAAAIZmVlX3JhdGUAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACaWQAAAAAAAYAAAAAAAAABG1lbW8AAAPoAAAAEAAAAAAAAAAJdGltZXN0YW1wAAAAAAAABgAAAAAAAAACdG8AAAAAABM=",
+        "AAAAAQAAAAAAAAAAAAAADVNwZW5kaW5nTGltaXQAAAAAAAADAAAAAAAAAAdwZXJfZGF5AAAAA+gAAAALAAAAAAAAAAlwZXJfZXZlbnQAAAAAAAPoAAAACwAAAAAAAAALcGVyX3BheW1lbnQAAAAD6AAAAAs=",
+        "AAAABQAAAAAAAAAAAAAADUJpbGxDYW5jZWxsZWQAAAAAAAABAAAADmJpbGxfY2FuY2VsbGVkAAAAAAADAAAAAAAAAAdiaWxsX2lkAAAAAAYAAAAAAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAhyZWZ1bmRlZAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADUVzY3Jvd0NyZWF0ZWQAAAAAAAABAAAADmVzY3Jvd19jcmVhdGVkAAAAAAAGAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAKcmVsZWFzZV9hdAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADUZlZXNXaXRoZHJhd24AAAAAAAABAAAADmZlZXNfd2l0aGRyYXduAAAAAAADAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAlvcmdhbml6ZXIAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADUZpYXRDb252ZXJ0ZWQAAAAAAAABAAAADmZpYXRfY29udmVydGVkAAAAAAAEAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAhjdXJyZW5jeQAAABEAAAAAAAAAAAAAAAtmaWF0X2Ftb3VudAAAAAALAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADVBheW1lbnRLZXlTZXQAAAAAAAABAAAAD3BheW1lbnRfa2V5X3NldAAAAAACAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAABAAAAAAAAAANrZXkAAAAD6AAAB9AAAAAKUGF5bWVudEtleQAAAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADVBheW91dENsYWltZWQAAAAAAAABAAAADnBheW91dF9jbGFpbWVkAAAAAAADAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADVN3YXBSb3V0ZXJTZXQAAAAAAAABAAAAD3N3YXBfcm91dGVyX3NldAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAAAAAAZyb3V0ZXIAAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADVRpcEZlZVJhdGVTZXQAAAAAAAABAAAAEHRpcF9mZWVfcmF0ZV9zZXQAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAhmZWVfcmF0ZQAAAAQAAAAAAAAAAg==",
         "AAAAAQAAAAAAAAAAAAAADkNvbnRyYWN0Q29uZmlnAAAAAAAEAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAEGRlZmF1bHRfZmVlX3JhdGUAAAAEAAAAAAAAAA1uZXh0X2V2ZW50X2lkAAAAAAAABgAAAAAAAAANdG9rZW5fYWRkcmVzcwAAAAAAABM=",
-        "AAAAAAAAADVJbmljaWFsaXphIG8gY29udHJhdG8gY29tIHRheGEgcGFkcsOjbywgYWRtaW4gZSB0b2tlbgAAAAAAAAppbml0aWFsaXplAAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAAEGRlZmF1bHRfZmVlX3JhdGUAAAAEAAAAAAAAAA10b2tlbl9hZGRyZXNzAAAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
-        "AAAAAAAAADJDb25zdWx0YSBjb25maWd1cmHDp8OjbyBkbyBjb250cmF0byAoYXBlbmFzIGFkbWluKQAAAAAACmdldF9jb25maWcAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAPpAAAH0AAAAA5Db250cmFjdENvbmZpZwAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
-        "AAAAAAAAACRBdHVhbGl6YSB0YXhhIHBhZHLDo28gKGFwZW5hcyBhZG1pbikAAAAXdXBkYXRlX2RlZmF1bHRfZmVlX3JhdGUAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAxuZXdfZmVlX3JhdGUAAAAEAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAQAAAAAAAAAAAAAADldhbGxldFNwZW5kaW5nAAAAAAADAAAAAAAAAANkYXkAAAAABgAAAAAAAAALc3BlbnRfdG9kYXkAAAAACwAAAAAAAAALc3BlbnRfdG90YWwAAAAACw==",
+        "AAAABQAAAAAAAAAAAAAADkVzY3Jvd0Rpc3B1dGVkAAAAAAABAAAAD2VzY3Jvd19kaXNwdXRlZAAAAAACAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADkVzY3Jvd1JlZnVuZGVkAAAAAAABAAAAD2VzY3Jvd19yZWZ1bmRlZAAAAAADAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADkVzY3Jvd1JlbGVhc2VkAAAAAAABAAAAD2VzY3Jvd19yZWxlYXNlZAAAAAADAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAKcGF5bWVudF9pZAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADkZpYXRQcmljaW5nU2V0AAAAAAABAAAAEGZpYXRfcHJpY2luZ19zZXQAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAdwcmljaW5nAAAAA+gAAAfQAAAAC0ZpYXRQcmljaW5nAAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADkludm9pY2VDcmVhdGVkAAAAAAABAAAAD2ludm9pY2VfY3JlYXRlZAAAAAAGAAAAAAAAAAppbnZvaWNlX2lkAAAAAAAGAAAAAAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAFcGF5ZWUAAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAEbWVtbwAAABAAAAAAAAAAAAAAAApleHBpcmVzX2F0AAAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADlBheW91dFNwbGl0U2V0AAAAAAABAAAAEHBheW91dF9zcGxpdF9zZXQAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAApyZWNpcGllbnRzAAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADlBvaW50c1JlZGVlbWVkAAAAAAABAAAAD3BvaW50c19yZWRlZW1lZAAAAAAFAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAAAAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAAAAAAAAAAAAZyZXdhcmQAAAAAB9AAAAANTG95YWx0eVJld2FyZAAAAAAAAAAAAAAAAAAABXZhbHVlAAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADlRpY2tldFByaWNlU2V0AAAAAAABAAAAEHRpY2tldF9wcmljZV9zZXQAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAVwcmljZQAAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAD1Jlc2FsZUNvbmZpZ1NldAAAAAABAAAAEXJlc2FsZV9jb25maWdfc2V0AAAAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAGY29uZmlnAAAAAAfQAAAADFJlc2FsZUNvbmZpZwAAAAAAAAAC",
+        "AAAAAAAAAEdUaXAgYW4gYXJ0aXN0OyB1c2VzIHRoZSBldmVudCdzIHRpcCBmZWUgcmF0ZSBpbnN0ZWFkIG9mIHRoZSByZWd1bGFyIG9uZQAAAAADdGlwAAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAEZnJvbQAAABMAAAAAAAAABmFydGlzdAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAAGAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAABQAAAAAAAAAAAAAAEEFydGlzdFJlZ2lzdGVyZWQAAAABAAAAEWFydGlzdF9yZWdpc3RlcmVkAAAAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAGYXJ0aXN0AAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEEJpbGxDb250cmlidXRpb24AAAABAAAAEWJpbGxfY29udHJpYnV0aW9uAAAAAAAABQAAAAAAAAAHYmlsbF9pZAAAAAAGAAAAAAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAALY29udHJpYnV0b3IAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAABmZ1bmRlZAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEEZlZVBvbGljeVVwZGF0ZWQAAAABAAAAEmZlZV9wb2xpY3lfdXBkYXRlZAAAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAIcm91bmRpbmcAAAfQAAAADFJvdW5kaW5nTW9kZQAAAAAAAAAAAAAAB21pbl9mZWUAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEEludm9pY2VDYW5jZWxsZWQAAAABAAAAEWludm9pY2VfY2FuY2VsbGVkAAAAAAAAAgAAAAAAAAAKaW52b2ljZV9pZAAAAAAABgAAAAAAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEExveWFsdHlDb25maWdTZXQAAAABAAAAEmxveWFsdHlfY29uZmlnX3NldAAAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAGY29uZmlnAAAAAAfQAAAADUxveWFsdHlDb25maWcAAAAAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEFNwZW5kaW5nTGltaXRTZXQAAAABAAAAEnNwZW5kaW5nX2xpbWl0X3NldAAAAAAABAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAGd2FsbGV0AAAAAAPoAAAAEwAAAAAAAAAAAAAABnNldF9ieQAAAAAAEwAAAAAAAAAAAAAABWxpbWl0AAAAAAAH0AAAAA1TcGVuZGluZ0xpbWl0AAAAAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEFRpY2tldEJhc2VVcmlTZXQAAAABAAAAE3RpY2tldF9iYXNlX3VyaV9zZXQAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAIYmFzZV91cmkAAAAQAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEFdhbGxldFJlZ2lzdGVyZWQAAAABAAAAEXdhbGxldF9yZWdpc3RlcmVkAAAAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAGd2FsbGV0AAAAAAATAAAAAQAAAAI=",
+        "AAAAAQAAAAAAAAAAAAAAEU9yZ2FuaXplclRyZWFzdXJ5AAAAAAAABAAAAAAAAAALYWNjdW11bGF0ZWQAAAAACwAAAAAAAAALZXZlbnRfY291bnQAAAAABAAAAAAAAAAMd2l0aGRyYXdhYmxlAAAACwAAAAAAAAAJd2l0aGRyYXduAAAAAAAACw==",
+        "AAAAAAAAABNORlQgY29sbGVjdGlvbiBuYW1lAAAAAARuYW1lAAAAAAAAAAEAAAAQ",
+        "AAAABQAAAAAAAAAAAAAAEVRpY2tldFRyYW5zZmVycmVkAAAAAAAAAQAAABJ0aWNrZXRfdHJhbnNmZXJyZWQAAAAAAAQAAAAAAAAACXRpY2tldF9pZAAAAAAAAAYAAAAAAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEVRyZWFzdXJ5V2l0aGRyYXduAAAAAAAAAQAAABJ0cmVhc3VyeV93aXRoZHJhd24AAAAAAAMAAAAAAAAACW9yZ2FuaXplcgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAAtldmVudF9jb3VudAAAAAAEAAAAAAAAAAI=",
+        "AAAAAAAAAH9SZXNnYXRhIHRva2VucyBlbnZpYWRvcyBwb3IgZW5nYW5vIChhcGVuYXMgYWRtaW4pLiBEbyB0b2tlbiBkbyBjb250cmF0bywKc8OzIG8gcXVlIGV4Y2VkZSBhcyBvYnJpZ2HDp8O1ZXMgcmVnaXN0cmFkYXMgcG9kZSBzYWlyAAAAAAVzd2VlcAAAAAAAAAMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAABQAAAAAAAAAAAAAAEkJpbGxTaGFyZVJlY2xhaW1lZAAAAAAAAQAAABRiaWxsX3NoYXJlX3JlY2xhaW1lZAAAAAQAAAAAAAAAB2JpbGxfaWQAAAAABgAAAAAAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAAAAAAC2NvbnRyaWJ1dG9yAAAAABMAAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEkV2ZW50U3RhdHVzQ2hhbmdlZAAAAAAAAQAAABRldmVudF9zdGF0dXNfY2hhbmdlZAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAAAAAACWlzX2FjdGl2ZQAAAAAAAAEAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAElBhdGhQYXltZW50U3dhcHBlZAAAAAAAAQAAABRwYXRoX3BheW1lbnRfc3dhcHBlZAAAAAUAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAAAAAABGZyb20AAAATAAAAAQAAAAAAAAAKc2VuZF90b2tlbgAAAAAAEwAAAAAAAAAAAAAACWFtb3VudF9pbgAAAAAAAAsAAAAAAAAAAAAAAAphbW91bnRfb3V0AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEldhbGxldFVucmVnaXN0ZXJlZAAAAAAAAQAAABN3YWxsZXRfdW5yZWdpc3RlcmVkAAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAAAAAABndhbGxldAAAAAAAEwAAAAEAAAAC",
+        "AAAAAAAAABVORlQgY29sbGVjdGlvbiBzeW1ib2wAAAAAAAAGc3ltYm9sAAAAAAAAAAAAAQAAABA=",
+        "AAAABQAAAAAAAAAAAAAAE0NvbnRyYWN0SW5pdGlhbGl6ZWQAAAAAAQAAABRjb250cmFjdF9pbml0aWFsaXplZAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAAEGRlZmF1bHRfZmVlX3JhdGUAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAE0Rpc2NvdW50Q29kZUNyZWF0ZWQAAAAAAQAAABVkaXNjb3VudF9jb2RlX2NyZWF0ZWQAAAAAAAAFAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAljb2RlX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAAAAAABGtpbmQAAAfQAAAADERpc2NvdW50S2luZAAAAAAAAAAAAAAACG1heF91c2VzAAAABAAAAAAAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAE0ZlZUF1dGhvcml6YXRpb25TZXQAAAAAAQAAABVmZWVfYXV0aG9yaXphdGlvbl9zZXQAAAAAAAACAAAAAAAAAAlmZWVfcGF5ZXIAAAAAAAATAAAAAQAAAAAAAAAObWF4X2ZlZV9hbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAE1Nlc3Npb25HcmFudENyZWF0ZWQAAAAAAQAAABVzZXNzaW9uX2dyYW50X2NyZWF0ZWQAAAAAAAAFAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAdncmFudG9yAAAAABMAAAABAAAAAAAAAAhkZWxlZ2F0ZQAAABMAAAAAAAAAAAAAAAphbW91bnRfY2FwAAAAAAALAAAAAAAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAE1Nlc3Npb25HcmFudFJldm9rZWQAAAAAAQAAABVzZXNzaW9uX2dyYW50X3Jldm9rZWQAAAAAAAADAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAdncmFudG9yAAAAABMAAAABAAAAAAAAAAhkZWxlZ2F0ZQAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAE1N1YnNjcmlwdGlvbkNyZWF0ZWQAAAAAAQAAABRzdWJzY3JpcHRpb25fY3JlYXRlZAAAAAYAAAAAAAAAD3N1YnNjcmlwdGlvbl9pZAAAAAAGAAAAAAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAAAAAAAAAAAFcGF5ZWUAAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAGcGVyaW9kAAAAAAAGAAAAAAAAAAI=",
+        "AAAAAAAAADhOdW1iZXIgb2YgdGlja2V0cyBvd25lZCBieSBhbiBhY2NvdW50LCBhY3Jvc3MgYWxsIGV2ZW50cwAAAAdiYWxhbmNlAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAE",
+        "AAAABQAAAAAAAAAAAAAAFERpc2NvdW50Q29kZVJlZGVlbWVkAAAAAQAAABZkaXNjb3VudF9jb2RlX3JlZGVlbWVkAAAAAAAFAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAAljb2RlX2hhc2gAAAAAAAPuAAAAIAAAAAAAAAAAAAAABndhbGxldAAAAAAAEwAAAAAAAAAAAAAACGRpc2NvdW50AAAACwAAAAAAAAAAAAAABHVzZXMAAAAEAAAAAAAAAAI=",
+        "AAAAAAAAABJRdWVyeSBhIHNwbGl0IGJpbGwAAAAAAAhnZXRfYmlsbAAAAAEAAAAAAAAAB2JpbGxfaWQAAAAABgAAAAEAAAPpAAAH0AAAAARCaWxsAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAABFPd25lciBvZiBhIHRpY2tldAAAAAAAAAhvd25lcl9vZgAAAAEAAAAAAAAACHRva2VuX2lkAAAABAAAAAEAAAPpAAAAEwAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAD1UcmFuc2ZlciBhIHRpY2tldCwgZ2F0ZWQgYnkgdGhlIGV2ZW50J3MgdHJhbnNmZXJhYmlsaXR5IHJ1bGVzAAAAAAAACHRyYW5zZmVyAAAAAwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAh0b2tlbl9pZAAAAAQAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAABQAAAAAAAAAAAAAAFURlZmF1bHRGZWVSYXRlVXBkYXRlZAAAAAAAAAEAAAAYZGVmYXVsdF9mZWVfcmF0ZV91cGRhdGVkAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAIb2xkX3JhdGUAAAAEAAAAAAAAAAAAAAAIbmV3X3JhdGUAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAFVBheW1lbnROb25jZUNhbmNlbGxlZAAAAAAAAAEAAAAXcGF5bWVudF9ub25jZV9jYW5jZWxsZWQAAAAAAgAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAFVN1YnNjcmlwdGlvbkNhbmNlbGxlZAAAAAAAAAEAAAAWc3Vic2NyaXB0aW9uX2NhbmNlbGxlZAAAAAAAAgAAAAAAAAAPc3Vic2NyaXB0aW9uX2lkAAAAAAYAAAAAAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAFVN1YnNjcmlwdGlvbkNvbGxlY3RlZAAAAAAAAAEAAAAWc3Vic2NyaXB0aW9uX2NvbGxlY3RlZAAAAAAABAAAAAAAAAAPc3Vic2NyaXB0aW9uX2lkAAAAAAYAAAAAAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAAAAAAAApwYXltZW50X2lkAAAAAAAGAAAAAAAAAAAAAAAIbmV4dF9kdWUAAAAGAAAAAAAAAAI=",
+        "AAAAAAAAABdRdWVyeSBldmVudCBpbmZvcm1hdGlvbgAAAAAJZ2V0X2V2ZW50AAAAAAAAAQAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAA+kAAAfQAAAABUV2ZW50AAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAADZWZXJpZmljYSBzZSB1bSBhcnRpc3RhIHBvZGUgcmVjZWJlciBnb3JqZXRhcyBubyBldmVudG8AAAAAAAlpc19hcnRpc3QAAAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABmFydGlzdAAAAAAAEwAAAAEAAAAB",
+        "AAAAAAAAAKNBYnJlIHVtYSBjb250YSBwYXJhIHNlciBkaXZpZGlkYSBlbnRyZSB2w6FyaWFzIGNhcnRlaXJhcyAoYXBlbmFzIG8gcmVjZWJlZG9yKTsKc2UgbsOjbyBmb3IgZmluYW5jaWFkYSBhdMOpIGBleHBpcmVzX2F0YCwgY2FkYSBjb250cmlidWludGUgcG9kZSByZWN1cGVyYXIgc3VhIHBhcnRlAAAAAAlvcGVuX2JpbGwAAAAAAAAFAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABXBheWVlAAAAAAAAEwAAAAAAAAAFdG90YWwAAAAAAAALAAAAAAAAAARtZW1vAAAD6AAAABAAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAEJNZXRhZGF0YSBVUkkgb2YgYSB0aWNrZXQ6IGV2ZW50IGJhc2UgVVJJIGZvbGxvd2VkIGJ5IHRoZSB0aWNrZXQgaWQAAAAAAAl0b2tlbl91cmkAAAAAAAABAAAAAAAAAAh0b2tlbl9pZAAAAAQAAAABAAAD6QAAABAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAABQAAAAAAAAAAAAAAFlNpZ25lZFBheW1lbnRTdWJtaXR0ZWQAAAAAAAEAAAAYc2lnbmVkX3BheW1lbnRfc3VibWl0dGVkAAAABAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAQAAAAAAAAAFbm9uY2UAAAAAAAAGAAAAAAAAAAAAAAAKcGF5bWVudF9pZAAAAAAABgAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAFldpdGhkcmF3YWxMaW1pdFVwZGF0ZWQAAAAAAAEAAAAYd2l0aGRyYXdhbF9saW1pdF91cGRhdGVkAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAJbGltaXRfYnBzAAAAAAAABAAAAAAAAAAC",
+        "AAAAAAAAAFxDb21wcmEgYXTDtG1pY2EgZGUgdW0gaW5ncmVzc28gbGlzdGFkbzsgbyByb3lhbHR5IGRvIG9yZ2FuaXphZG9yIHZhaSBwYXJhIGFzIHRheGFzIGRvIGV2ZW50bwAAAApidXlfdGlja2V0AAAAAAACAAAAAAAAAAl0aWNrZXRfaWQAAAAAAAAGAAAAAAAAAAVidXllcgAAAAAAABMAAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAClRdWVyeSBjb250cmFjdCBjb25maWd1cmF0aW9uIChhZG1pbiBvbmx5KQAAAAAAAApnZXRfY29uZmlnAAAAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAD6QAAB9AAAAAOQ29udHJhY3RDb25maWcAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAABlRdWVyeSBhbiBlc2Nyb3dlZCBwYXltZW50AAAAAAAACmdldF9lc2Nyb3cAAAAAAAEAAAAAAAAACWVzY3Jvd19pZAAAAAAAAAYAAAABAAAD6QAAB9AAAAAGRXNjcm93AAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAADJDb25zdWx0YSBzYWxkbyBkZSBwb250b3MgZGUgdW1hIGNhcnRlaXJhIG5vIGV2ZW50bwAAAAAACmdldF9wb2ludHMAAAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAGd2FsbGV0AAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAA5RdWVyeSBhIHRpY2tldAAAAAAACmdldF90aWNrZXQAAAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAYAAAABAAAD6QAAB9AAAAAGVGlja2V0AAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAADpJbml0aWFsaXplIGNvbnRyYWN0IHdpdGggZGVmYXVsdCBmZWUgcmF0ZSwgYWRtaW4gYW5kIHRva2VuAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAABBkZWZhdWx0X2ZlZV9yYXRlAAAABAAAAAAAAAANdG9rZW5fYWRkcmVzcwAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAE9Ub2tlbiBhbW91bnQgYSBmaWF0IGFtb3VudCBjb252ZXJ0cyB0byByaWdodCBub3csIGZvciBjaG9vc2luZyBhIHNsaXBwYWdlIGJvdW5kAAAAAApxdW90ZV9maWF0AAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAAC2ZpYXRfYW1vdW50AAAAAAsAAAABAAAD6QAAAAsAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAABQAAAAAAAAAAAAAAF0V2ZW50QWxsb3dhbmNlSW5jcmVhc2VkAAAAAAEAAAAZZXZlbnRfYWxsb3dhbmNlX2luY3JlYXNlZAAAAAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAAAAAACW9yZ2FuaXplcgAAAAAAABMAAAABAAAAAAAAAAVhZGRlZAAAAAAAAAsAAAAAAAAAAAAAAAlhbGxvd2FuY2UAAAAAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAFlDYW5jZWxhIHVtYSBjb250YSBhaW5kYSBuw6NvIGZpbmFuY2lhZGEgZSBkZXZvbHZlIGFzIGNvbnRyaWJ1acOnw7VlcyAoYXBlbmFzIG8gcmVjZWJlZG9yKQAAAAAAAAtjYW5jZWxfYmlsbAAAAAABAAAAAAAAAAdiaWxsX2lkAAAAAAYAAAABAAAD6QAAAAsAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAEBDb2xsZWN0IHRoZSBwYXltZW50IGR1ZSBmb3IgdGhlIGN1cnJlbnQgcGVyaW9kIChhbnlvbmUgY2FuIGNhbGwpAAAAC2NvbGxlY3RfZHVlAAAAAAEAAAAAAAAAD3N1YnNjcmlwdGlvbl9pZAAAAAAGAAAAAQAAA+kAAAAGAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAEpRdWVyeSBhbiBpbnZvaWNlIChvcGVuIGludm9pY2VzIHBhc3QgdGhlaXIgZXhwaXJ5IGFyZSByZXBvcnRlZCBhcyBleHBpcmVkKQAAAAAAC2dldF9pbnZvaWNlAAAAAAEAAAAAAAAACmludm9pY2VfaWQAAAAAAAYAAAABAAAD6QAAB9AAAAAHSW52b2ljZQAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAABxRdWVyeSBhIHBheW1lbnQgcmVjb3JkIGJ5IGlkAAAAC2dldF9wYXltZW50AAAAAAEAAAAAAAAACnBheW1lbnRfaWQAAAAAAAYAAAABAAAD6QAAB9AAAAANUGF5bWVudFJlY29yZAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAD5MaXN0YSB0b2RvcyBvcyBldmVudG9zIChsaW1pdGFkbyBwYXJhIGV2aXRhciBwcm9ibGVtYXMgZGUgZ2FzKQAAAAAAC2xpc3RfZXZlbnRzAAAAAAEAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPpAAAD6gAAB9AAAAAFRXZlbnQAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAADlMaXN0IGEgdGlja2V0IGZvciByZXNhbGUsIHVwIHRvIHRoZSBvcmdhbml6ZXIncyBwcmljZSBjYXAAAAAAAAALbGlzdF90aWNrZXQAAAAAAgAAAAAAAAAJdGlja2V0X2lkAAAAAAAABgAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAADxQYXkgYW4gb3BlbiBpbnZvaWNlIChzYW1lIHJ1bGVzIGFuZCBmZWVzIGFzIGBldmVudF9wYXltZW50YCkAAAALcGF5X2ludm9pY2UAAAAAAgAAAAAAAAAKaW52b2ljZV9pZAAAAAAABgAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAACRTYWNhIGEgY290YSBkZSB1bWEgcGFydGUgZGEgZGl2aXPDo28AAAAMY2xhaW1fcGF5b3V0AAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAQAAA+kAAAALAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
         "AAAAAAAAABxDcmlhIHVtIG5vdm8gZXZlbnRvL2Zlc3RpdmFsAAAADGNyZWF0ZV9ldmVudAAAAAMAAAAAAAAACW9yZ2FuaXplcgAAAAAAABMAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAhmZWVfcmF0ZQAAA+gAAAAEAAAAAQAAA+kAAAAGAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
-        "AAAAAAAAAF5DcmlhIHVtIGV2ZW50byBlIGF1dG9yaXphIGF1dG9tYXRpY2FtZW50ZSBvIGNvbnRyYXRvIGEgZ2FzdGFyIHRva2VucyBkbyBvcmdhbml6YWRvciBwYXJhIHRheGFzAAAAAAAbY3JlYXRlX2V2ZW50X3dpdGhfYWxsb3dhbmNlAAAAAAQAAAAAAAAACW9yZ2FuaXplcgAAAAAAABMAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAhmZWVfcmF0ZQAAA+gAAAAEAAAAAAAAAA1tYXhfYWxsb3dhbmNlAAAAAAAACwAAAAEAAAPpAAAABgAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAACxRdWVyeSB0aGUgZmVlcyBvZiBhbGwgZXZlbnRzIG9mIGFuIG9yZ2FuaXplcgAAAAxnZXRfdHJlYXN1cnkAAAABAAAAAAAAAAlvcmdhbml6ZXIAAAAAAAATAAAAAQAAA+kAAAfQAAAAEU9yZ2FuaXplclRyZWFzdXJ5AAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAFFFbWl0ZSB1bSBpbmdyZXNzbyBwYXJhIHVtYSBjYXJ0ZWlyYSwgcmVnaXN0cmFuZG8tYSBubyBldmVudG8gKGFwZW5hcyBvcmdhbml6YWRvcikAAAAAAAAMaXNzdWVfdGlja2V0AAAAAwAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAACmZhY2VfdmFsdWUAAAAAAAsAAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAMlQYXkgd2l0aCBhbm90aGVyIHRva2VuOiBgcm91dGUuYW1vdW50X2luYCBvZiBgcm91dGUuc2VuZF90b2tlbmAgaXMgc3dhcHBlZCBpbnRvIHRoZSBldmVudAp0b2tlbiB0aHJvdWdoIHRoZSByb3V0ZXIgYW5kIHRoZSBvdXRwdXQsIGF0IGxlYXN0IGByb3V0ZS5taW5fb3V0YCwgaXMgcGFpZCB0byBgdG9gIGxpa2UgYQpyZWd1bGFyIGV2ZW50IHBheW1lbnQAAAAAAAAMcGF0aF9wYXltZW50AAAABQAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABXJvdXRlAAAAAAAH0AAAAAlTd2FwUm91dGUAAAAAAAAAAAAABG1lbW8AAAPoAAAAEAAAAAEAAAPpAAAABgAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAFBNYWtlIHBheW1lbnQgZm9yIGEgc3BlY2lmaWMgZXZlbnQ7IHRoZSBldmVudCdzIGZlZSBtb2RlIGRlY2lkZXMgd2hvIHBheXMgdGhlIGZlZQAAAA1ldmVudF9wYXltZW50AAAAAAAABQAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAEbWVtbwAAA+gAAAAQAAAAAQAAA+kAAAAGAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAEBDaGVjayB3aGV0aGVyIGEgcGF5ZXIncyBub25jZSB3YXMgYWxyZWFkeSBzdWJtaXR0ZWQgb3IgY2FuY2VsbGVkAAAADWlzX25vbmNlX3VzZWQAAAAAAAACAAAAAAAAAAVwYXllcgAAAAAAABMAAAAAAAAABW5vbmNlAAAAAAAABgAAAAEAAAAB",
+        "AAAAAAAAAFJSZWRlZW0gcG9pbnRzIGF0IGFuIGV2ZW50IGZvciBhIGZlZSBkaXNjb3VudCBvciBhIHZvdWNoZXI7IHJldHVybnMgdGhlIHRva2VuIHZhbHVlAAAAAAANcmVkZWVtX3BvaW50cwAAAAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAGd2FsbGV0AAAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAAAAAABnJld2FyZAAAAAAH0AAAAA1Mb3lhbHR5UmV3YXJkAAAAAAAAAQAAA+kAAAALAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAADNDYW5jZWxhIHVtYSBjb2JyYW7Dp2EgZW0gYWJlcnRvIChhcGVuYXMgbyB2ZW5kZWRvcikAAAAADmNhbmNlbF9pbnZvaWNlAAAAAAABAAAAAAAAAAppbnZvaWNlX2lkAAAAAAAGAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAD5Db21wYXJlIHJlY29yZGVkIGxpYWJpbGl0aWVzIHdpdGggdGhlIGNvbnRyYWN0J3MgdG9rZW4gYmFsYW5jZQAAAAAADmNoZWNrX3NvbHZlbmN5AAAAAAAAAAAAAQAAA+kAAAfQAAAACFNvbHZlbmN5AAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAADtCdXllciBjb25maXJtcyByZWNlaXB0IGFuZCByZWxlYXNlcyB0aGUgZnVuZHMgdG8gdGhlIHNlbGxlcgAAAAAOY29uZmlybV9lc2Nyb3cAAAAAAAEAAAAAAAAACWVzY3Jvd19pZAAAAAAAAAYAAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAD9DcmlhIHVtYSBjb2JyYW7Dp2EgcXVlIHBvZGUgc2VyIHBhZ2EgcG9yIGlkIChhcGVuYXMgbyB2ZW5kZWRvcikAAAAADmNyZWF0ZV9pbnZvaWNlAAAAAAAFAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABXBheWVlAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAARtZW1vAAAAEAAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAEAAAPpAAAABgAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAFRCdXllciBvcGVucyBhIGRpc3B1dGUgd2l0aGluIHRoZSB3aW5kb3csIGxlYXZpbmcgdGhlIGRlY2lzaW9uIHRvIHRoZSBldmVudCBvcmdhbml6ZXIAAAAOZGlzcHV0ZV9lc2Nyb3cAAAAAAAEAAAAAAAAACWVzY3Jvd19pZAAAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAGRQYWdhIHBhcmEgbyBjb250cmF0byBlbSBjdXN0w7NkaWE7IG8gdmVuZGVkb3IgcmVjZWJlIGFww7NzIGNvbmZpcm1hw6fDo28gb3UgZmltIGRhIGphbmVsYSBkZSBkaXNwdXRhAAAADmVzY3Jvd19wYXltZW50AAAAAAAGAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABWJ1eWVyAAAAAAAAEwAAAAAAAAAGc2VsbGVyAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAADmRpc3B1dGVfd2luZG93AAAAAAAGAAAAAAAAAARtZW1vAAAD6AAAABAAAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAACZDb25zdWx0YSB0YXhhcyBhY3VtdWxhZGFzIGRlIHVtIGV2ZW50bwAAAAAADmdldF9ldmVudF9mZWVzAAAAAAABAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAACw==",
+        "AAAAAAAAAEtRdWVyeSB3aGF0J3MgbGVmdCBvZiB0aGUgZmVlX3BheWVyJ3MgYnVkZ2V0IGZvciBwYXltZW50X3dpdGhfYXV0aF9mZWVfcGF5ZXIAAAAADmdldF9mZWVfYnVkZ2V0AAAAAAABAAAAAAAAAAlmZWVfcGF5ZXIAAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAADJRdWVyeSB0aGUgZmVlIGNyZWRpdCBhIHdhbGxldCBoYXMgbGVmdCBpbiBhbiBldmVudAAAAAAADmdldF9mZWVfY3JlZGl0AAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABndhbGxldAAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAD5RdWVyeSB0aGUgZmVlIHBvbGljeSAoZGVmYXVsdDogZmxvb3Igcm91bmRpbmcsIG5vIG1pbmltdW0gZmVlKQAAAAAADmdldF9mZWVfcG9saWN5AAAAAAAAAAAAAQAAB9AAAAAJRmVlUG9saWN5AAAA",
+        "AAAAAAAAAFtMaWJlcmEgb3MgZnVuZG9zIGFvIHZlbmRlZG9yIGFww7NzIG8gZmltIGRhIGphbmVsYSBkZSBkaXNwdXRhIChxdWFscXVlciBwZXNzb2EgcG9kZSBjaGFtYXIpAAAAAA5yZWxlYXNlX2VzY3JvdwAAAAAAAQAAAAAAAAAJZXNjcm93X2lkAAAAAAAABgAAAAEAAAPpAAAABgAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAADNEZWZpbmUgYXJyZWRvbmRhbWVudG8gZSB0YXhhIG3DrW5pbWEgKGFwZW5hcyBhZG1pbikAAAAADnNldF9mZWVfcG9saWN5AAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABnBvbGljeQAAAAAH0AAAAAlGZWVQb2xpY3kAAAAAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAADJRdWVyeSB0b3RhbCB0aXBzIHJlY2VpdmVkIGJ5IGFuIGFydGlzdCBpbiBhbiBldmVudAAAAAAAD2dldF9hcnRpc3RfdGlwcwAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABmFydGlzdAAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAFxUb3RhbCBxdWUgbyBjb250cmF0byBkZXZlOiB0YXhhcyBkZSBldmVudG9zLCByZXBhc3NlcyBuw6NvIHNhY2Fkb3MsIGVzY3Jvd3MgZSBjb250YXMgYWJlcnRhcwAAAA9nZXRfbGlhYmlsaXRpZXMAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAADFRdWVyeSB0aGUga2V5IGEgd2FsbGV0IHNpZ25zIHBheW1lbnQgaW50ZW50cyB3aXRoAAAAAAAAD2dldF9wYXltZW50X2tleQAAAAABAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAABAAAD6AAAB9AAAAAKUGF5bWVudEtleQAA",
+        "AAAAAAAAADNRdWVyeSB0aGUgREVYIHJvdXRlciB1c2VkIGZvciBwYXRoIHBheW1lbnRzLCBpZiBhbnkAAAAAD2dldF9zd2FwX3JvdXRlcgAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAEdCdXkgYSB0aWNrZXQgZnJvbSB0aGUgb3JnYW5pemVyLCBvcHRpb25hbGx5IHdpdGggYSB0aWNrZXQgZGlzY291bnQgY29kZQAAAAAPcHVyY2hhc2VfdGlja2V0AAAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAFYnV5ZXIAAAAAAAATAAAAAAAAAARjb2RlAAAD6AAAAA4AAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAEhSZWdpc3RyYSB1bSBhcnRpc3RhIHBhcmEgcmVjZWJlciBnb3JqZXRhcyBubyBldmVudG8gKGFwZW5hcyBvcmdhbml6YWRvcikAAAAPcmVnaXN0ZXJfYXJ0aXN0AAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAGYXJ0aXN0AAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAFhSZWdpc3RyYSAob3UgcmVtb3ZlIGNvbSBOb25lKSBhIGNoYXZlIHF1ZSBhc3NpbmEgYXMgaW50ZW7Dp8O1ZXMgZGUgcGFnYW1lbnRvIGRhIGNhcnRlaXJhAAAAD3NldF9wYXltZW50X2tleQAAAAACAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAAAAAAAA2tleQAAAAPoAAAH0AAAAApQYXltZW50S2V5AAAAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAE9EZWZpbmUgbyByb3RlYWRvciBkZSBERVggdXNhZG8gbm9zIHBhZ2FtZW50b3MgY29tIHRyb2NhIGRlIHRva2VuIChhcGVuYXMgYWRtaW4pAAAAAA9zZXRfc3dhcF9yb3V0ZXIAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAZyb3V0ZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAEFUcmFuc2ZlcmUgdW0gaW5ncmVzc28gcGFyYSBvdXRyYSBjYXJ0ZWlyYSBzZW0gdmVuZGEgKGFwZW5hcyBkb25vKQAAAAAAAA90cmFuc2Zlcl90aWNrZXQAAAAAAwAAAAAAAAAJdGlja2V0X2lkAAAAAAAABgAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAADdRdWVyeSBhbiBldmVudCdzIGZpYXQgcHJpY2luZyAoTm9uZSA9IHByaWNlZCBpbiB0b2tlbnMpAAAAABBnZXRfZmlhdF9wcmljaW5nAAAAAQAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAA+gAAAfQAAAAC0ZpYXRQcmljaW5nAA==",
+        "AAAAAAAAAEVRdWVyeSB0aGUgcGF5b3V0IHNwbGl0IG9mIGFuIGV2ZW50IChlbXB0eSA9IGZlZXMgZ28gdG8gdGhlIG9yZ2FuaXplcikAAAAAAAAQZ2V0X3BheW91dF9zcGxpdAAAAAEAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAPqAAAH0AAAAAtQYXlvdXRTaGFyZQA=",
+        "AAAAAAAAABRRdWVyeSBhIHN1YnNjcmlwdGlvbgAAABBnZXRfc3Vic2NyaXB0aW9uAAAAAQAAAAAAAAAPc3Vic2NyaXB0aW9uX2lkAAAAAAYAAAABAAAD6QAAB9AAAAAMU3Vic2NyaXB0aW9uAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAADxRdWVyeSB0aGUgcHJpbWFyeSBzYWxlIHByaWNlIG9mIHRpY2tldHMsIGlmIHRoZXkgYXJlIG9uIHNhbGUAAAAQZ2V0X3RpY2tldF9wcmljZQAAAAEAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAPoAAAACw==",
+        "AAAAAAAAACJRdWVyeSB0aGUgZmVlIHJhdGUgY2hhcmdlZCBvbiB0aXBzAAAAAAAQZ2V0X3RpcF9mZWVfcmF0ZQAAAAEAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAAE",
         "AAAAAAAAADBBdGl2YSBvdSBkZXNhdGl2YSB1bSBldmVudG8gKGFwZW5hcyBvcmdhbml6YWRvcikAAAAQc2V0X2V2ZW50X3N0YXR1cwAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAJaXNfYWN0aXZlAAAAAAAAAQAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
-        "AAAAAAAAACNDb25zdWx0YSBpbmZvcm1hw6fDtWVzIGRlIHVtIGV2ZW50bwAAAAAJZ2V0X2V2ZW50AAAAAAAAAQAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAA+kAAAfQAAAABUV2ZW50AAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAGZEZW5vbWluYSBvcyBwcmXDp29zIGRvIGV2ZW50byBlbSBtb2VkYSBmaWR1Y2nDoXJpYSwgb3Ugdm9sdGEgcGFyYSB0b2tlbnMgY29tIE5vbmUgKGFwZW5hcyBvcmdhbml6YWRvcikAAAAAABBzZXRfZmlhdF9wcmljaW5nAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAdwcmljaW5nAAAAA+gAAAfQAAAAC0ZpYXRQcmljaW5nAAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAJ5EZWZpbmUgY29tbyBhcyB0YXhhcyBkbyBldmVudG8gc8OjbyBkaXZpZGlkYXMgZW50cmUgcGFyY2Vpcm9zIChhcGVuYXMgb3JnYW5pemFkb3IpLgpBcyBjb3RhcyBkZXZlbSBzb21hciAxMDAwMCBiYXNpcyBwb2ludHM7IHVtYSBsaXN0YSB2YXppYSByZW1vdmUgYSBkaXZpc8OjbwAAAAAAEHNldF9wYXlvdXRfc3BsaXQAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABnNoYXJlcwAAAAAD6gAAB9AAAAALUGF5b3V0U2hhcmUAAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAIFEZWZpbmUgbyBwcmXDp28gZGUgdmVuZGEgZGUgaW5ncmVzc29zIHBlbG8gY29udHJhdG8gKGFwZW5hcyBvcmdhbml6YWRvcik7CmVtIG1vZWRhIGZpZHVjacOhcmlhIHF1YW5kbyBvIGV2ZW50byB0ZW0gcHJlw6dvIGVtIGZpYXQAAAAAAAAQc2V0X3RpY2tldF9wcmljZQAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAFcHJpY2UAAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAADxTZXQgdGhlIGZlZSByYXRlIGNoYXJnZWQgb24gdGlwcyAob3JnYW5pemVyIG9ubHksIGRlZmF1bHQgMCkAAAAQc2V0X3RpcF9mZWVfcmF0ZQAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAIZmVlX3JhdGUAAAAEAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAACFRdWVyeSBhIGRpc2NvdW50IGNvZGUgYnkgaXRzIGhhc2gAAAAAAAARZ2V0X2Rpc2NvdW50X2NvZGUAAAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACWNvZGVfaGFzaAAAAAAAA+4AAAAgAAAAAQAAA+kAAAfQAAAADERpc2NvdW50Q29kZQAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
         "AAAAAAAAABhDb25zdWx0YSBldmVudG8gcG9yIG5vbWUAAAARZ2V0X2V2ZW50X2J5X25hbWUAAAAAAAABAAAAAAAAAARuYW1lAAAAEAAAAAEAAAPpAAAH0AAAAAVFdmVudAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
-        "AAAAAAAAAD5MaXN0YSB0b2RvcyBvcyBldmVudG9zIChsaW1pdGFkbyBwYXJhIGV2aXRhciBwcm9ibGVtYXMgZGUgZ2FzKQAAAAAAC2xpc3RfZXZlbnRzAAAAAAEAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPpAAAD6gAAB9AAAAAFRXZlbnQAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
-        "AAAAAAAAACZDb25zdWx0YSB0YXhhcyBhY3VtdWxhZGFzIGRlIHVtIGV2ZW50bwAAAAAADmdldF9ldmVudF9mZWVzAAAAAAABAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAACw==",
+        "AAAAAAAAAEFRdWVyeSByZXNhbGUgcnVsZXMgKGRlZmF1bHQ6IHRyYW5zZmVyYWJsZSwgbm8gbWFya3VwLCBubyByb3lhbHR5KQAAAAAAABFnZXRfcmVzYWxlX2NvbmZpZwAAAAAAAAEAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAfQAAAADFJlc2FsZUNvbmZpZw==",
+        "AAAAAAAAABVRdWVyeSBhIHNlc3Npb24gZ3JhbnQAAAAAAAARZ2V0X3Nlc3Npb25fZ3JhbnQAAAAAAAADAAAAAAAAAAdncmFudG9yAAAAABMAAAAAAAAACGRlbGVnYXRlAAAAEwAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAA+kAAAfQAAAADFNlc3Npb25HcmFudAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAADpEZWZpbmUgYXMgcmVncmFzIGRlIHJldmVuZGEgZG8gZXZlbnRvIChhcGVuYXMgb3JnYW5pemFkb3IpAAAAAAARc2V0X3Jlc2FsZV9jb25maWcAAAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABmNvbmZpZwAAAAAH0AAAAAxSZXNhbGVDb25maWcAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAH1TYWNhIGRlIHVtYSB2ZXogYXMgdGF4YXMgZGUgdG9kb3Mgb3MgZXZlbnRvcyBkbyBvcmdhbml6YWRvcjoKdHVkbyBkb3MgZXZlbnRvcyBpbmF0aXZvcyBlIGF0w6kgbyBsaW1pdGUgY29uZmlndXJhZG8gZG9zIGF0aXZvcwAAAAAAABF3aXRoZHJhd19hbGxfZmVlcwAAAAAAAAEAAAAAAAAACW9yZ2FuaXplcgAAAAAAABMAAAABAAAD6QAAAAsAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAFdDb250cmlidXRlIGEgc2hhcmUgdG8gYW4gb3BlbiBiaWxsOyB0aGUgY29udHJpYnV0aW9uIHRoYXQgY29tcGxldGVzIGl0IHNldHRsZXMgdGhlIGJpbGwAAAAAEmNvbnRyaWJ1dGVfdG9fYmlsbAAAAAAAAwAAAAAAAAAHYmlsbF9pZAAAAAAGAAAAAAAAAAtjb250cmlidXRvcgAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAG5FdmVudCBwYXltZW50IG9mIGEgZmlhdCBhbW91bnQsIGNvbnZlcnRlZCBhdCB0aGUgb3JhY2xlIHByaWNlOyBmYWlscyBpZiBpdCBjb3N0cyBtb3JlIHRoYW4gYG1heF9hbW91bnRgIHRva2VucwAAAAAAEmV2ZW50X3BheW1lbnRfZmlhdAAAAAAABgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAAC2ZpYXRfYW1vdW50AAAAAAsAAAAAAAAACm1heF9hbW91bnQAAAAAAAsAAAAAAAAABG1lbW8AAAPoAAAAEAAAAAEAAAPpAAAABgAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAD1RdWVyeSB3aG8gcGF5cyB0aGUgZmVlIG9uIGV2ZW50IHBheW1lbnRzIChkZWZhdWx0OiByZWNpcGllbnQpAAAAAAAAEmdldF9ldmVudF9mZWVfbW9kZQAAAAAAAQAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAQAAB9AAAAAHRmVlTW9kZQA=",
+        "AAAAAAAAADtRdWVyeSB0aGUgbG95YWx0eSBzZXR0aW5ncyBvZiBhbiBldmVudCAoZGVmYXVsdDogbm8gcG9pbnRzKQAAAAASZ2V0X2xveWFsdHlfY29uZmlnAAAAAAABAAAAAAAAAAhldmVudF9pZAAAAAYAAAABAAAH0AAAAA1Mb3lhbHR5Q29uZmlnAAAA",
+        "AAAAAAAAAFRRdWVyeSB0aGUgZWZmZWN0aXZlIGNhcHMgb2YgYSB3YWxsZXQgKHRoZSB0aWdodGVzdCBvZiBvcmdhbml6ZXIgYW5kIHNlbGYtc2V0IGxpbWl0cykAAAASZ2V0X3NwZW5kaW5nX2xpbWl0AAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABndhbGxldAAAAAAAEwAAAAEAAAfQAAAADVNwZW5kaW5nTGltaXQAAAA=",
+        "AAAAAAAAAE5EZXZvbHZlIGEgcGFydGUgZGUgdW0gY29udHJpYnVpbnRlIGRlIHVtYSBjb250YSBxdWUgZXhwaXJvdSBzZW0gc2VyIGZpbmFuY2lhZGEAAAAAABJyZWNsYWltX2JpbGxfc2hhcmUAAAAAAAIAAAAAAAAAB2JpbGxfaWQAAAAABgAAAAAAAAALY29udHJpYnV0b3IAAAAAEwAAAAEAAAPpAAAACwAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAEVEZWZpbmUgcXVlbSBwYWdhIGEgdGF4YSBub3MgcGFnYW1lbnRvcyBkbyBldmVudG8gKGFwZW5hcyBvcmdhbml6YWRvcikAAAAAAAASc2V0X2V2ZW50X2ZlZV9tb2RlAAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABG1vZGUAAAfQAAAAB0ZlZU1vZGUAAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAD1Db25maWd1cmEgbyBwcm9ncmFtYSBkZSBwb250b3MgZG8gZXZlbnRvIChhcGVuYXMgb3JnYW5pemFkb3IpAAAAAAAAEnNldF9sb3lhbHR5X2NvbmZpZwAAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAZjb25maWcAAAAAB9AAAAANTG95YWx0eUNvbmZpZwAAAAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAACJDYW5jZWwgYSBzdWJzY3JpcHRpb24gKHBheWVyIG9ubHkpAAAAAAATY2FuY2VsX3N1YnNjcmlwdGlvbgAAAAABAAAAAAAAAA9zdWJzY3JpcHRpb25faWQAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAKJDcmlhIHVtYSBhc3NpbmF0dXJhIChleC46IHBhc3NlIGRlIHRlbXBvcmFkYSk7IGEgcHJpbWVpcmEgY29icmFuw6dhIHZlbmNlIGltZWRpYXRhbWVudGUKTm90ZTogaW5jcmVhc2VzIHRoZSBwYXllcidzIHRva2VuIGFsbG93YW5jZSB0byB0aGUgY29udHJhY3QgYnkgYGFsbG93YW5jZWAAAAAAABNjcmVhdGVfc3Vic2NyaXB0aW9uAAAAAAYAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAAAAAAVwYXllZQAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAGcGVyaW9kAAAAAAAGAAAAAAAAAAlhbGxvd2FuY2UAAAAAAAALAAAAAQAAA+kAAAAGAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAERBcnRpc3RhcyBkbyBldmVudG8gb3JkZW5hZG9zIHBlbG8gdG90YWwgZGUgZ29yamV0YXMgKG1haW9yIHByaW1laXJvKQAAABNnZXRfdGlwX2xlYWRlcmJvYXJkAAAAAAEAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAPqAAAH0AAAAApBcnRpc3RUaXBzAAA=",
+        "AAAAAAAAAFVRdWVyeSBob3cgbXVjaCBhIHdhbGxldCBoYXMgc3BlbnQgd2l0aGluIGFuIGV2ZW50IChzcGVudF90b2RheSBpcyByZXNldCBvbiBhIG5ldyBkYXkpAAAAAAAAE2dldF93YWxsZXRfc3BlbmRpbmcAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAABAAAH0AAAAA5XYWxsZXRTcGVuZGluZwAA",
+        "AAAAAAAAAExEZWZpbmUgYSBVUkkgYmFzZSBkb3MgbWV0YWRhZG9zIGRvcyBpbmdyZXNzb3MgZG8gZXZlbnRvIChhcGVuYXMgb3JnYW5pemFkb3IpAAAAE3NldF90aWNrZXRfYmFzZV91cmkAAAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAhiYXNlX3VyaQAAABAAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAFBQZXJtaXRlIGFvIG9yZ2FuaXphZG9yIHNhY2FyIHRheGFzIGFjdW11bGFkYXMgKGFwZW5hcyBzZSBldmVudG8gZXN0aXZlciBpbmF0aXZvKQAAABN3aXRoZHJhd19ldmVudF9mZWVzAAAAAAEAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAPpAAAACwAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAElJbnZhbGlkYXRlIGEgbm9uY2Ugc28gYW4gaW50ZW50IHNpZ25lZCB3aXRoIGl0IGNhbiBubyBsb25nZXIgYmUgc3VibWl0dGVkAAAAAAAAFGNhbmNlbF9wYXltZW50X25vbmNlAAAAAgAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAAAAAAVub25jZQAAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAFRDcmlhIHVtIGPDs2RpZ28gcHJvbW9jaW9uYWwgYSBwYXJ0aXIgZG8gaGFzaCBTSEEtMjU2IGRvIGPDs2RpZ28gKGFwZW5hcyBvcmdhbml6YWRvcikAAAAUY3JlYXRlX2Rpc2NvdW50X2NvZGUAAAAFAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAACWNvZGVfaGFzaAAAAAAAA+4AAAAgAAAAAAAAAARraW5kAAAH0AAAAAxEaXNjb3VudEtpbmQAAAAAAAAACG1heF91c2VzAAAABAAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAATtBdXRvcml6YSB1bWEgY2hhdmUgZGVsZWdhZGEgYSBwYWdhciBlbSBub21lIGRhIGNhcnRlaXJhIGRlbnRybyBkZSB1bSBldmVudG8KTm90ZTogcmFpc2VzIHRoZSBncmFudG9yJ3MgdG9rZW4gYWxsb3dhbmNlIHRvIHRoZSBjb250cmFjdCBieSBgYW1vdW50X2NhcGA7IHJlLWNyZWF0aW5nCmEgZ3JhbnQgcmVwbGFjZXMgdGhlIHByZXZpb3VzIG9uZSdzIHVuc3BlbnQgYWxsb3dhbmNlLiBUaGUgY2FwIGl0c2VsZiBpcyBlbmZvcmNlZCBmcm9tCnRoZSBncmFudCdzIGBzcGVudGAsIG5vdCBmcm9tIHRoZSBhbGxvd2FuY2Ugb3RoZXIgZmVhdHVyZXMgc2hhcmUAAAAAFGNyZWF0ZV9zZXNzaW9uX2dyYW50AAAABQAAAAAAAAAHZ3JhbnRvcgAAAAATAAAAAAAAAAhkZWxlZ2F0ZQAAABMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAKYW1vdW50X2NhcAAAAAAACwAAAAAAAAAKZXhwaXJlc19hdAAAAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAADNRdWVyeSB0aGUgZmVlcyBhIHNwbGl0IHBhcnR5IGNhbiBwdWxsIGZyb20gYW4gZXZlbnQAAAAAFGdldF9jbGFpbWFibGVfcGF5b3V0AAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAACtMaXN0YSBvcyBldmVudG9zIGNyaWFkb3MgcG9yIHVtIG9yZ2FuaXphZG9yAAAAABRnZXRfb3JnYW5pemVyX2V2ZW50cwAAAAEAAAAAAAAACW9yZ2FuaXplcgAAAAAAABMAAAABAAAD6gAAAAY=",
+        "AAAAAAAAACxDaGVjayBpZiBhIHdhbGxldCBpcyByZWdpc3RlcmVkIGZvciBhbiBldmVudAAAABRpc193YWxsZXRfcmVnaXN0ZXJlZAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAGd2FsbGV0AAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAElSZXZva2UgYSBzZXNzaW9uIGdyYW50IChncmFudG9yIG9ubHkpOyBpdHMgdW5zcGVudCBhbGxvd2FuY2UgaXMgd2l0aGRyYXduAAAAAAAAFHJldm9rZV9zZXNzaW9uX2dyYW50AAAAAwAAAAAAAAAHZ3JhbnRvcgAAAAATAAAAAAAAAAhkZWxlZ2F0ZQAAABMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAChSZW1vdmUgYSB0aWNrZXQgZnJvbSByZXNhbGUgKG93bmVyIG9ubHkpAAAAFWNhbmNlbF90aWNrZXRfbGlzdGluZwAAAAAAAAEAAAAAAAAACXRpY2tldF9pZAAAAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAC1RdWVyeSBob3cgbXVjaCBhIHdhbGxldCBjb250cmlidXRlZCB0byBhIGJpbGwAAAAAAAAVZ2V0X2JpbGxfY29udHJpYnV0aW9uAAAAAAAAAgAAAAAAAAAHYmlsbF9pZAAAAAAGAAAAAAAAAAtjb250cmlidXRvcgAAAAATAAAAAQAAAAs=",
         "AAAAAAAAADFDb25zdWx0YSBvIGFsbG93YW5jZSBkbyBmZWVfcGF5ZXIgcGFyYSBvIGNvbnRyYXRvAAAAAAAAFWdldF9mZWVfYXV0aG9yaXphdGlvbgAAAAAAAAEAAAAAAAAACWZlZV9wYXllcgAAAAAAABMAAAABAAAACw==",
-        "AAAAAAAAADJSZWdpc3RyYSB1bWEgY2FydGVpcmEgcGFyYSBwYXJ0aWNpcGFyIGRlIHVtIGV2ZW50bwAAAAAAGXJlZ2lzdGVyX3dhbGxldF9mb3JfZXZlbnQAAAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABndhbGxldAAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
-        "AAAAAAAAACxSZW1vdmUgcmVnaXN0cm8gZGUgdW1hIGNhcnRlaXJhIGRlIHVtIGV2ZW50bwAAABx1bnJlZ2lzdGVyX3dhbGxldF9mcm9tX2V2ZW50AAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
-        "AAAAAAAAADhWZXJpZmljYSBzZSB1bWEgY2FydGVpcmEgZXN0w6EgcmVnaXN0cmFkYSBwYXJhIHVtIGV2ZW50bwAAABRpc193YWxsZXRfcmVnaXN0ZXJlZAAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAGd2FsbGV0AAAAAAATAAAAAQAAAAE=",
-        "AAAAAAAAAEZSZWFsaXphIHBhZ2FtZW50byBwYXJhIHVtIGV2ZW50byBlc3BlY8OtZmljbyB1c2FuZG8gZnVuZG9zIGRhIGNhcnRlaXJhAAAAAAANZXZlbnRfcGF5bWVudAAAAAAAAAQAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
-        "AAAAAAAAAEpSZWFsaXphIHBhZ2FtZW50byBnZXJhbCAoc2VtIGV2ZW50byBlc3BlY8OtZmljbykgLSBtYW50w6ltIGNvbXBhdGliaWxpZGFkZQAAAAAAHHBheW1lbnRfd2l0aF90aGlyZF9wYXJ0eV9mZWUAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACWZlZV9wYXllcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
-        "AAAAAAAAAEBSZWFsaXphIHBhZ2FtZW50byBjb20gZmVlX3BheWVyIHByw6ktYXV0b3JpemFkbyAoc2VtIGFzc2luYXR1cmEpAAAAG3BheW1lbnRfd2l0aF9hdXRoX2ZlZV9wYXllcgAAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACWZlZV9wYXllcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
-        "AAAAAAAAAHZBdXRvcml6YSBvIGNvbnRyYXRvIGEgdXNhciB0b2tlbnMgZG8gdXN1w6FyaW8gcGFyYSBwYWdhciBmZWVzCk5vdGE6IEVzdGEgZnVuw6fDo28gY2hhbWEgYXBwcm92ZSgpIG5vIGNvbnRyYXRvIGRlIHRva2VuAAAAAAAWYXV0aG9yaXplX2ZlZV9wYXltZW50cwAAAAAAAgAAAAAAAAAJZmVlX3BheWVyAAAAAAAAEwAAAAAAAAAObWF4X2ZlZV9hbW91bnQAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
-        "AAAAAAAAADdSZW1vdmUgYXV0b3JpemHDp8OjbyBwYXJhIHBhZ2FtZW50byBhdXRvbcOhdGljbyBkZSBmZWVzAAAAABhyZXZva2VfZmVlX2F1dGhvcml6YXRpb24AAAABAAAAAAAAAAlmZWVfcGF5ZXIAAAAAAAATAAAAAA==",
+        "AAAAAAAAAI9TZXR0bGUgYSBwYXltZW50IGludGVudCBzaWduZWQgb2ZmLWNoYWluIGJ5IHRoZSBwYXllci4gQW55b25lIGNhbiBzdWJtaXQgaXQ7IHRoZSBhbW91bnQKaXMgZHJhd24gZnJvbSB0aGUgYWxsb3dhbmNlIHRoZSBwYXllciBnYXZlIHRoZSBjb250cmFjdAAAAAAVc3VibWl0X3NpZ25lZF9wYXltZW50AAAAAAAAAgAAAAAAAAAGaW50ZW50AAAAAAfQAAAADVBheW1lbnRJbnRlbnQAAAAAAAAAAAAACXNpZ25hdHVyZQAAAAAAA+4AAABAAAAAAQAAA+kAAAAGAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAALRBdXRob3JpemUgY29udHJhY3QgdG8gdXNlIHVzZXIncyB0b2tlbnMgdG8gcGF5IGZlZXMgb2YgcGF5bWVudF93aXRoX2F1dGhfZmVlX3BheWVyLgpTZXRzIHRoZSBmZWUgYnVkZ2V0IChyZXBsYWNpbmcgdGhlIHByZXZpb3VzIG9uZSkgYW5kIG1vdmVzIHRoZSB0b2tlbiBhbGxvd2FuY2UgYnkgdGhlIGRpZmZlcmVuY2UAAAAWYXV0aG9yaXplX2ZlZV9wYXltZW50cwAAAAAAAgAAAAAAAAAJZmVlX3BheWVyAAAAAAAAEwAAAAAAAAAObWF4X2ZlZV9hbW91bnQAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAJVPcmdhbml6YWRvciBhcmJpdHJhIGEgZGlzcHV0YTogcmVlbWJvbHNhIG8gY29tcHJhZG9yIChzZW0gdGF4YSkgb3UgcGFnYSBvIHZlbmRlZG9yOwpxdWVtIGFkaWFudG91IGEgdGF4YSAoY29tcHJhZG9yIG91IG9yZ2FuaXphZG9yKSBhIHJlY2ViZSBkZSB2b2x0YQAAAAAAABZyZXNvbHZlX2VzY3Jvd19kaXNwdXRlAAAAAAACAAAAAAAAAAllc2Nyb3dfaWQAAAAAAAAGAAAAAAAAAAxyZWZ1bmRfYnV5ZXIAAAABAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAEdCeXRlcyB0aGUgcGF5ZXIgc2lnbnMgZm9yIGFuIGludGVudDogdGhlIFhEUiBvZiAodGhpcyBjb250cmFjdCwgaW50ZW50KQAAAAAWc2lnbmVkX3BheW1lbnRfcGF5bG9hZAAAAAAAAQAAAAAAAAAGaW50ZW50AAAAAAfQAAAADVBheW1lbnRJbnRlbnQAAAAAAAABAAAADg==",
+        "AAAAAAAAALVTZXR0bGUgYSBwYXltZW50IGludGVudCBzaWduZWQgd2l0aCBhIHBhc3NrZXkgKFdlYkF1dGhuIGFzc2VydGlvbiBmcm9tIGBuYXZpZ2F0b3IuY3JlZGVudGlhbHMuZ2V0YCkuClRoZSBjaGFsbGVuZ2UgaXMgdGhlIFNIQS0yNTYgb2YgdGhlIHBheWxvYWQsIGJhc2U2NHVybC1lbmNvZGVkIGluIGNsaWVudERhdGFKU09OAAAAAAAAFnN1Ym1pdF9wYXNza2V5X3BheW1lbnQAAAAAAAQAAAAAAAAABmludGVudAAAAAAH0AAAAA1QYXltZW50SW50ZW50AAAAAAAAAAAAABJhdXRoZW50aWNhdG9yX2RhdGEAAAAAAA4AAAAAAAAAEGNsaWVudF9kYXRhX2pzb24AAAAOAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAEAAAPpAAAABgAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAFBQYWdhbWVudG8gZmVpdG8gcGVsYSBjaGF2ZSBkZWxlZ2FkYSwgZGViaXRhbmRvIGEgY2FydGVpcmEgcXVlIGNvbmNlZGV1IGEgc2Vzc8OjbwAAABdkZWxlZ2F0ZWRfZXZlbnRfcGF5bWVudAAAAAAGAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAAB2dyYW50b3IAAAAAEwAAAAAAAAAIZGVsZWdhdGUAAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAARtZW1vAAAD6AAAABAAAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAExFdmVudCBwYXltZW50IHdpdGggYSBmZWUgd2FpdmVyIGNvZGU6IHRoZSByZWNpcGllbnQgcmVjZWl2ZXMgdGhlIGZ1bGwgYW1vdW50AAAAF2V2ZW50X3BheW1lbnRfd2l0aF9jb2RlAAAAAAYAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABG1lbW8AAAPoAAAAEAAAAAAAAAAEY29kZQAAAA4AAAABAAAD6QAAAAYAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAADNBbGxvd3MgYSB3YWxsZXQgdG8gc2V0IGl0cyBvd24gY2FwcyB3aXRoaW4gYW4gZXZlbnQAAAAAF3NldF9zZWxmX3NwZW5kaW5nX2xpbWl0AAAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAGd2FsbGV0AAAAAAATAAAAAAAAAAVsaW1pdAAAAAAAB9AAAAANU3BlbmRpbmdMaW1pdAAAAAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAACRVcGRhdGUgZGVmYXVsdCBmZWUgcmF0ZSAoYWRtaW4gb25seSkAAAAXdXBkYXRlX2RlZmF1bHRfZmVlX3JhdGUAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAxuZXdfZmVlX3JhdGUAAAAEAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
         "AAAAAAAAAExQZXJtaXRlIGFvIG9yZ2FuaXphZG9yIGF1bWVudGFyIG8gYWxsb3dhbmNlIHBhcmEgY29icmlyIG1haXMgdGF4YXMgZG8gZXZlbnRvAAAAGGluY3JlYXNlX2V2ZW50X2FsbG93YW5jZQAAAAIAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAUYWRkaXRpb25hbF9hbGxvd2FuY2UAAAALAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
-        "AAAAAAAAAFBQZXJtaXRlIGFvIG9yZ2FuaXphZG9yIHNhY2FyIHRheGFzIGFjdW11bGFkYXMgKGFwZW5hcyBzZSBldmVudG8gZXN0aXZlciBpbmF0aXZvKQAAABN3aXRoZHJhd19ldmVudF9mZWVzAAAAAAEAAAAAAAAACGV2ZW50X2lkAAAABgAAAAEAAAPpAAAACwAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==" ]),
+        "AAAAAAAAAGFSZW1vdmUgYXV0aG9yaXphdGlvbiBmb3IgYXV0b21hdGljIGZlZSBwYXltZW50LCB3aXRoZHJhd2luZyB0aGUgdW5zcGVudCBidWRnZXQgZnJvbSB0aGUgYWxsb3dhbmNlAAAAAAAAGHJldm9rZV9mZWVfYXV0aG9yaXphdGlvbgAAAAEAAAAAAAAACWZlZV9wYXllcgAAAAAAABMAAAAA",
+        "AAAAAAAAAFlEZWZpbmUgb3MgbGltaXRlcyBwYWRyw6NvIGRlIGdhc3RvIHBhcmEgdG9kYXMgYXMgY2FydGVpcmFzIGRvIGV2ZW50byAoYXBlbmFzIG9yZ2FuaXphZG9yKQAAAAAAABhzZXRfZXZlbnRfc3BlbmRpbmdfbGltaXQAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABWxpbWl0AAAAAAAH0AAAAA1TcGVuZGluZ0xpbWl0AAAAAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAEpSZWdpc3RyYSB1bWEgY2FydGVpcmEgcGFyYSBwYXJ0aWNpcGFyIGRlIHVtIGV2ZW50byAob3JnYW5pemFkb3IgcGFnYSB0YXhhKQAAAAAAGXJlZ2lzdGVyX3dhbGxldF9mb3JfZXZlbnQAAAAAAAACAAAAAAAAAAhldmVudF9pZAAAAAYAAAAAAAAABndhbGxldAAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAFREZWZpbmUgbGltaXRlcyBkZSBnYXN0byBwYXJhIHVtYSBjYXJ0ZWlyYSBlc3BlY8OtZmljYSBkbyBldmVudG8gKGFwZW5hcyBvcmdhbml6YWRvcikAAAAZc2V0X3dhbGxldF9zcGVuZGluZ19saW1pdAAAAAAAAAMAAAAAAAAACGV2ZW50X2lkAAAABgAAAAAAAAAGd2FsbGV0AAAAAAATAAAAAAAAAAVsaW1pdAAAAAAAB9AAAAANU3BlbmRpbmdMaW1pdAAAAAAAAAEAAAPpAAAD7QAAAAAAAAfQAAAADUNvbnRyYWN0RXJyb3IAAAA=",
+        "AAAAAAAAAF5DcmlhIHVtIGV2ZW50byBlIGF1dG9yaXphIGF1dG9tYXRpY2FtZW50ZSBvIGNvbnRyYXRvIGEgZ2FzdGFyIHRva2VucyBkbyBvcmdhbml6YWRvciBwYXJhIHRheGFzAAAAAAAbY3JlYXRlX2V2ZW50X3dpdGhfYWxsb3dhbmNlAAAAAAQAAAAAAAAACW9yZ2FuaXplcgAAAAAAABMAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAhmZWVfcmF0ZQAAA+gAAAAEAAAAAAAAAA1tYXhfYWxsb3dhbmNlAAAAAAAACwAAAAEAAAPpAAAABgAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAElRdWVyeSB0aGUgZnJhY3Rpb24gb2YgYWN0aXZlLWV2ZW50IGZlZXMgdGhhdCBjYW4gYmUgd2l0aGRyYXduIChkZWZhdWx0IDApAAAAAAAAG2dldF9hY3RpdmVfd2l0aGRyYXdhbF9saW1pdAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAJFNYWtlIHBheW1lbnQgd2l0aCBwcmUtYXV0aG9yaXplZCBmZWVfcGF5ZXIgKG5vIHNpZ25hdHVyZSkuCkEgdGhpcmQtcGFydHkgZmVlX3BheWVyJ3MgZmVlIGlzIGNoYXJnZWQgdG8gdGhlIGJ1ZGdldCBzZXQgd2l0aCBhdXRob3JpemVfZmVlX3BheW1lbnRzAAAAAAAAG3BheW1lbnRfd2l0aF9hdXRoX2ZlZV9wYXllcgAAAAAFAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACWZlZV9wYXllcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAEbWVtbwAAA+gAAAAQAAAAAQAAA+kAAAAGAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAFlGcmHDp8OjbyAoZW0gYmFzaXMgcG9pbnRzKSBkYXMgdGF4YXMgZGUgZXZlbnRvcyBhdGl2b3MgcXVlIHBvZGUgc2VyIHNhY2FkYSAoYXBlbmFzIGFkbWluKQAAAAAAABtzZXRfYWN0aXZlX3dpdGhkcmF3YWxfbGltaXQAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAlsaW1pdF9icHMAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==",
+        "AAAAAAAAAJ9NYWtlIGdlbmVyYWwgcGF5bWVudCAod2l0aG91dCBzcGVjaWZpYyBldmVudCkgLSBtYWludGFpbnMgY29tcGF0aWJpbGl0eS4KVGhlIGZlZV9wYXllciBzaWduczsgdGhlIGZlZSBtb2RlIGZvbGxvd3MgZnJvbSB3aG8gaXQgaXMgKHNlZSBwcm9jZXNzX2dlbmVyYWxfcGF5bWVudCkAAAAAHHBheW1lbnRfd2l0aF90aGlyZF9wYXJ0eV9mZWUAAAAFAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAACWZlZV9wYXllcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAEbWVtbwAAA+gAAAAQAAAAAQAAA+kAAAAGAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAERSZW1vdmUgcmVnaXN0cm8gZGUgdW1hIGNhcnRlaXJhIGRlIHVtIGV2ZW50byAob3JnYW5pemFkb3IgcGFnYSB0YXhhKQAAABx1bnJlZ2lzdGVyX3dhbGxldF9mcm9tX2V2ZW50AAAAAgAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAZ3YWxsZXQAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAH0AAAAA1Db250cmFjdEVycm9yAAAA",
+        "AAAAAAAAAFZCdXkgYSB0aWNrZXQgcGF5aW5nIGF0IG1vc3QgYG1heF9wcmljZWAgdG9rZW5zIChzbGlwcGFnZSBib3VuZCBmb3IgZmlhdC1wcmljZWQgZXZlbnRzKQAAAAAAHnB1cmNoYXNlX3RpY2tldF93aXRoX21heF9wcmljZQAAAAAABAAAAAAAAAAIZXZlbnRfaWQAAAAGAAAAAAAAAAVidXllcgAAAAAAABMAAAAAAAAABGNvZGUAAAPoAAAADgAAAAAAAAAJbWF4X3ByaWNlAAAAAAAACwAAAAEAAAPpAAAABgAAB9AAAAANQ29udHJhY3RFcnJvcgAAAA==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    initialize: this.txFromJSON<Result<void>>,
+    tip: this.txFromJSON<Result<u64>>,
+        name: this.txFromJSON<string>,
+        sweep: this.txFromJSON<Result<void>>,
+        symbol: this.txFromJSON<string>,
+        balance: this.txFromJSON<u32>,
+        get_bill: this.txFromJSON<Result<Bill>>,
+        owner_of: this.txFromJSON<Result<string>>,
+        transfer: this.txFromJSON<Result<void>>,
+        get_event: this.txFromJSON<Result<Event>>,
+        is_artist: this.txFromJSON<boolean>,
+        open_bill: this.txFromJSON<Result<u64>>,
+        token_uri: this.txFromJSON<Result<string>>,
+        buy_ticket: this.txFromJSON<Result<u64>>,
         get_config: this.txFromJSON<Result<ContractConfig>>,
-        update_default_fee_rate: this.txFromJSON<Result<void>>,
+        get_escrow: this.txFromJSON<Result<Escrow>>,
+        get_points: this.txFromJSON<i128>,
+        get_ticket: this.txFromJSON<Result<Ticket>>,
+        initialize: this.txFromJSON<Result<void>>,
+        quote_fiat: this.txFromJSON<Result<i128>>,
+        cancel_bill: this.txFromJSON<Result<i128>>,
+        collect_due: this.txFromJSON<Result<u64>>,
+        get_invoice: this.txFromJSON<Result<Invoice>>,
+        get_payment: this.txFromJSON<Result<PaymentRecord>>,
+        list_events: this.txFromJSON<Result<Array<Event>>>,
+        list_ticket: this.txFromJSON<Result<void>>,
+        pay_invoice: this.txFromJSON<Result<void>>,
+        claim_payout: this.txFromJSON<Result<i128>>,
         create_event: this.txFromJSON<Result<u64>>,
-        create_event_with_allowance: this.txFromJSON<Result<u64>>,
+        get_treasury: this.txFromJSON<Result<OrganizerTreasury>>,
+        issue_ticket: this.txFromJSON<Result<u64>>,
+        path_payment: this.txFromJSON<Result<u64>>,
+        event_payment: this.txFromJSON<Result<u64>>,
+        is_nonce_used: this.txFromJSON<boolean>,
+        redeem_points: this.txFromJSON<Result<i128>>,
+        cancel_invoice: this.txFromJSON<Result<void>>,
+        check_solvency: this.txFromJSON<Result<Solvency>>,
+        confirm_escrow: this.txFromJSON<Result<u64>>,
+        create_invoice: this.txFromJSON<Result<u64>>,
+        dispute_escrow: this.txFromJSON<Result<void>>,
+        escrow_payment: this.txFromJSON<Result<u64>>,
+        get_event_fees: this.txFromJSON<i128>,
+        get_fee_budget: this.txFromJSON<i128>,
+        get_fee_credit: this.txFromJSON<i128>,
+        get_fee_policy: this.txFromJSON<FeePolicy>,
+        release_escrow: this.txFromJSON<Result<u64>>,
+        set_fee_policy: this.txFromJSON<Result<void>>,
+        get_artist_tips: this.txFromJSON<i128>,
+        get_liabilities: this.txFromJSON<i128>,
+        get_payment_key: this.txFromJSON<Option<PaymentKey>>,
+        get_swap_router: this.txFromJSON<Option<string>>,
+        purchase_ticket: this.txFromJSON<Result<u64>>,
+        register_artist: this.txFromJSON<Result<void>>,
+        set_payment_key: this.txFromJSON<Result<void>>,
+        set_swap_router: this.txFromJSON<Result<void>>,
+        transfer_ticket: this.txFromJSON<Result<void>>,
+        get_fiat_pricing: this.txFromJSON<Option<FiatPricing>>,
+        get_payout_split: this.txFromJSON<Array<PayoutShare>>,
+        get_subscription: this.txFromJSON<Result<Subscription>>,
+        get_ticket_price: this.txFromJSON<Option<i128>>,
+        get_tip_fee_rate: this.txFromJSON<u32>,
         set_event_status: this.txFromJSON<Result<void>>,
-        get_event: this.txFromJSON<Result<Event>>,
+        set_fiat_pricing: this.txFromJSON<Result<void>>,
+        set_payout_split: this.txFromJSON<Result<void>>,
+        set_ticket_price: this.txFromJSON<Result<void>>,
+        set_tip_fee_rate: this.txFromJSON<Result<void>>,
+        get_discount_code: this.txFromJSON<Result<DiscountCode>>,
         get_event_by_name: this.txFromJSON<Result<Event>>,
-        list_events: this.txFromJSON<Result<Array<Event>>>,
-        get_event_fees: this.txFromJSON<i128>,
-        get_fee_authorization: this.txFromJSON<i128>,
-        register_wallet_for_event: this.txFromJSON<Result<void>>,
-        unregister_wallet_from_event: this.txFromJSON<Result<void>>,
+        get_resale_config: this.txFromJSON<ResaleConfig>,
+        get_session_grant: this.txFromJSON<Result<SessionGrant>>,
+        set_resale_config: this.txFromJSON<Result<void>>,
+        withdraw_all_fees: this.txFromJSON<Result<i128>>,
+        contribute_to_bill: this.txFromJSON<Result<void>>,
+        event_payment_fiat: this.txFromJSON<Result<u64>>,
+        get_event_fee_mode: this.txFromJSON<FeeMode>,
+        get_loyalty_config: this.txFromJSON<LoyaltyConfig>,
+        get_spending_limit: this.txFromJSON<SpendingLimit>,
+        reclaim_bill_share: this.txFromJSON<Result<i128>>,
+        set_event_fee_mode: this.txFromJSON<Result<void>>,
+        set_loyalty_config: this.txFromJSON<Result<void>>,
+        cancel_subscription: this.txFromJSON<Result<void>>,
+        create_subscription: this.txFromJSON<Result<u64>>,
+        get_tip_leaderboard: this.txFromJSON<Array<ArtistTips>>,
+        get_wallet_spending: this.txFromJSON<WalletSpending>,
+        set_ticket_base_uri: this.txFromJSON<Result<void>>,
+        withdraw_event_fees: this.txFromJSON<Result<i128>>,
+        cancel_payment_nonce: this.txFromJSON<Result<void>>,
+        create_discount_code: this.txFromJSON<Result<void>>,
+        create_session_grant: this.txFromJSON<Result<void>>,
+        get_claimable_payout: this.txFromJSON<i128>,
+        get_organizer_events: this.txFromJSON<Array<u64>>,
         is_wallet_registered: this.txFromJSON<boolean>,
-        event_payment: this.txFromJSON<Result<void>>,
-        payment_with_third_party_fee: this.txFromJSON<Result<void>>,
-        payment_with_auth_fee_payer: this.txFromJSON<Result<void>>,
+        revoke_session_grant: this.txFromJSON<Result<void>>,
+        cancel_ticket_listing: this.txFromJSON<Result<void>>,
+        get_bill_contribution: this.txFromJSON<i128>,
+        get_fee_authorization: this.txFromJSON<i128>,
+        submit_signed_payment: this.txFromJSON<Result<u64>>,
         authorize_fee_payments: this.txFromJSON<Result<void>>,
-        revoke_fee_authorization: this.txFromJSON<null>,
+        resolve_escrow_dispute: this.txFromJSON<Result<void>>,
+        signed_payment_payload: this.txFromJSON<Buffer>,
+        submit_passkey_payment: this.txFromJSON<Result<u64>>,
+        delegated_event_payment: this.txFromJSON<Result<u64>>,
+        event_payment_with_code: this.txFromJSON<Result<u64>>,
+        set_self_spending_limit: this.txFromJSON<Result<void>>,
+        update_default_fee_rate: this.txFromJSON<Result<void>>,
         increase_event_allowance: this.txFromJSON<Result<void>>,
-        withdraw_event_fees: this.txFromJSON<Result<i128>>
+        revoke_fee_authorization: this.txFromJSON<null>,
+        set_event_spending_limit: this.txFromJSON<Result<void>>,
+        register_wallet_for_event: this.txFromJSON<Result<void>>,
+        set_wallet_spending_limit: this.txFromJSON<Result<void>>,
+        create_event_with_allowance: this.txFromJSON<Result<u64>>,
+        get_active_withdrawal_limit: this.txFromJSON<u32>,
+        payment_with_auth_fee_payer: this.txFromJSON<Result<u64>>,
+        set_active_withdrawal_limit: this.txFromJSON<Result<void>>,
+        payment_with_third_party_fee: this.txFromJSON<Result<u64>>,
+        unregister_wallet_from_event: this.txFromJSON<Result<void>>,
+        purchase_ticket_with_max_price: this.txFromJSON<Result<u64>>
   }
 }
\ No newline at end of file
@@ -16,6 +16,59 @@ fn main() {
         {"type": "result", "ok": "void", "error": "ContractError"}
       ]
     },
+    {
+      "name": "get_config",
+      "inputs": [
+        {"name": "admin", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "ContractConfig", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "update_default_fee_rate",
+      "inputs": [
+        {"name": "admin", "type": "address"},
+        {"name": "new_fee_rate", "type": "u32"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "set_fee_policy",
+      "inputs": [
+        {"name": "admin", "type": "address"},
+        {"name": "policy", "type": "FeePolicy"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_fee_policy",
+      "inputs": [],
+      "outputs": [
+        {"type": "FeePolicy"}
+      ]
+    },
+    {
+      "name": "set_swap_router",
+      "inputs": [
+        {"name": "admin", "type": "address"},
+        {"name": "router", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_swap_router",
+      "inputs": [],
+      "outputs": [
+        {"type": "option", "value": "address"}
+      ]
+    },
     {
       "name": "create_event",
       "inputs": [
@@ -39,6 +92,70 @@ fn main() {
         {"type": "result", "ok": "u64", "error": "ContractError"}
       ]
     },
+    {
+      "name": "set_event_status",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "is_active", "type": "bool"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_event",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "Event", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_event_by_name",
+      "inputs": [
+        {"name": "name", "type": "string"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "Event", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "list_events",
+      "inputs": [
+        {"name": "limit", "type": "u32"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "vec<Event>", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_event_fees",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "i128"}
+      ]
+    },
+    {
+      "name": "get_payment",
+      "inputs": [
+        {"name": "payment_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "PaymentRecord", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_fee_authorization",
+      "inputs": [
+        {"name": "fee_payer", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "i128"}
+      ]
+    },
     {
       "name": "register_wallet_for_event",
       "inputs": [
@@ -60,172 +177,1862 @@ fn main() {
       ]
     },
     {
-      "name": "event_payment",
+      "name": "is_wallet_registered",
       "inputs": [
         {"name": "event_id", "type": "u64"},
-        {"name": "from", "type": "address"},
-        {"name": "to", "type": "address"},
-        {"name": "amount", "type": "i128"}
+        {"name": "wallet", "type": "address"}
       ],
       "outputs": [
-        {"type": "result", "ok": "void", "error": "ContractError"}
+        {"type": "bool"}
       ]
     },
     {
-      "name": "payment",
+      "name": "set_event_spending_limit",
       "inputs": [
-        {"name": "from", "type": "address"},
-        {"name": "to", "type": "address"},
-        {"name": "amount", "type": "i128"},
-        {"name": "fee_payer", "type": "address"}
+        {"name": "event_id", "type": "u64"},
+        {"name": "limit", "type": "SpendingLimit"}
       ],
       "outputs": [
         {"type": "result", "ok": "void", "error": "ContractError"}
       ]
     },
     {
-      "name": "withdraw_event_fees",
+      "name": "set_wallet_spending_limit",
       "inputs": [
-        {"name": "event_id", "type": "u64"}
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"},
+        {"name": "limit", "type": "SpendingLimit"}
       ],
       "outputs": [
-        {"type": "result", "ok": "i128", "error": "ContractError"}
+        {"type": "result", "ok": "void", "error": "ContractError"}
       ]
     },
     {
-      "name": "authorize_fee_payments",
+      "name": "set_self_spending_limit",
       "inputs": [
-        {"name": "fee_payer", "type": "address"},
-        {"name": "max_fee_amount", "type": "i128"}
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"},
+        {"name": "limit", "type": "SpendingLimit"}
       ],
       "outputs": [
         {"type": "result", "ok": "void", "error": "ContractError"}
       ]
     },
     {
-      "name": "increase_event_allowance",
+      "name": "get_spending_limit",
       "inputs": [
         {"name": "event_id", "type": "u64"},
-        {"name": "additional_allowance", "type": "i128"}
+        {"name": "wallet", "type": "address"}
       ],
       "outputs": [
-        {"type": "result", "ok": "void", "error": "ContractError"}
+        {"type": "SpendingLimit"}
       ]
     },
     {
-      "name": "get_event",
+      "name": "get_wallet_spending",
       "inputs": [
-        {"name": "event_id", "type": "u64"}
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"}
       ],
       "outputs": [
-        {"type": "result", "ok": "Event", "error": "ContractError"}
+        {"type": "WalletSpending"}
       ]
     },
     {
-      "name": "get_config",
+      "name": "create_session_grant",
       "inputs": [
-        {"name": "admin", "type": "address"}
+        {"name": "grantor", "type": "address"},
+        {"name": "delegate", "type": "address"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "amount_cap", "type": "i128"},
+        {"name": "expires_at", "type": "u64"}
       ],
       "outputs": [
-        {"type": "ContractConfig"}
+        {"type": "result", "ok": "void", "error": "ContractError"}
       ]
     },
     {
-      "name": "get_event_fees",
+      "name": "revoke_session_grant",
       "inputs": [
+        {"name": "grantor", "type": "address"},
+        {"name": "delegate", "type": "address"},
         {"name": "event_id", "type": "u64"}
       ],
       "outputs": [
-        {"type": "i128"}
+        {"type": "result", "ok": "void", "error": "ContractError"}
       ]
     },
     {
-      "name": "get_fee_authorization",
+      "name": "get_session_grant",
       "inputs": [
-        {"name": "fee_payer", "type": "address"}
+        {"name": "grantor", "type": "address"},
+        {"name": "delegate", "type": "address"},
+        {"name": "event_id", "type": "u64"}
       ],
       "outputs": [
-        {"type": "i128"}
+        {"type": "result", "ok": "SessionGrant", "error": "ContractError"}
       ]
     },
     {
-      "name": "is_wallet_registered",
+      "name": "delegated_event_payment",
       "inputs": [
         {"name": "event_id", "type": "u64"},
+        {"name": "grantor", "type": "address"},
+        {"name": "delegate", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "memo", "type": "option", "value": "string"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "set_payment_key",
+      "inputs": [
+        {"name": "wallet", "type": "address"},
+        {"name": "key", "type": "option", "value": "PaymentKey"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_payment_key",
+      "inputs": [
         {"name": "wallet", "type": "address"}
       ],
       "outputs": [
-        {"type": "bool"}
+        {"type": "option", "value": "PaymentKey"}
       ]
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "Event",
-      "type": "struct",
-      "fields": [
-        {"name": "id", "type": "u64"},
-        {"name": "name", "type": "string"},
-        {"name": "organizer", "type": "address"},
-        {"name": "fee_rate", "type": "u32"},
-        {"name": "is_active", "type": "bool"},
-        {"name": "created_at", "type": "u64"},
-        {"name": "total_volume", "type": "i128"}
+      "name": "signed_payment_payload",
+      "inputs": [
+        {"name": "intent", "type": "PaymentIntent"}
+      ],
+      "outputs": [
+        {"type": "bytes"}
       ]
     },
     {
-      "name": "ContractConfig",
-      "type": "struct",
-      "fields": [
-        {"name": "default_fee_rate", "type": "u32"},
-        {"name": "admin", "type": "address"},
-        {"name": "next_event_id", "type": "u64"},
-        {"name": "token_address", "type": "address"}
+      "name": "submit_signed_payment",
+      "inputs": [
+        {"name": "intent", "type": "PaymentIntent"},
+        {"name": "signature", "type": "bytes64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
       ]
     },
     {
-      "name": "ContractError",
-      "type": "enum",
-      "cases": [
-        {"name": "FeeRateExceeds10Percent", "value": 1},
-        {"name": "AmountMustBePositive", "value": 2},
-        {"name": "ContractNotInitialized", "value": 3},
-        {"name": "InsufficientBalanceFromSender", "value": 4},
-        {"name": "InsufficientAllowance", "value": 5},
-        {"name": "EventNotFound", "value": 6},
-        {"name": "EventNotActive", "value": 7},
-        {"name": "NotEventOrganizer", "value": 8},
-        {"name": "EventNameTooLong", "value": 9},
-        {"name": "EventAlreadyExists", "value": 10},
-        {"name": "AlreadyInitialized", "value": 11},
-        {"name": "EventStillActive", "value": 12},
-        {"name": "WalletNotRegistered", "value": 13},
-        {"name": "WalletAlreadyRegistered", "value": 14},
-        {"name": "OrganizerCannotRegister", "value": 15},
-        {"name": "NotAdmin", "value": 16}
+      "name": "submit_passkey_payment",
+      "inputs": [
+        {"name": "intent", "type": "PaymentIntent"},
+        {"name": "authenticator_data", "type": "bytes"},
+        {"name": "client_data_json", "type": "bytes"},
+        {"name": "signature", "type": "bytes64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
       ]
-    }
-  ],
-  "events": [
+    },
     {
-      "name": "EventCreated",
-      "fields": [
-        {"name": "event_id", "type": "u64"},
-        {"name": "name", "type": "string"},
-        {"name": "organizer", "type": "address"},
-        {"name": "fee_rate", "type": "u32"}
+      "name": "cancel_payment_nonce",
+      "inputs": [
+        {"name": "payer", "type": "address"},
+        {"name": "nonce", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
       ]
     },
     {
-      "name": "PaymentEvent",
-      "fields": [
+      "name": "is_nonce_used",
+      "inputs": [
+        {"name": "payer", "type": "address"},
+        {"name": "nonce", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "bool"}
+      ]
+    },
+    {
+      "name": "create_invoice",
+      "inputs": [
         {"name": "event_id", "type": "u64"},
-        {"name": "from", "type": "address"},
-        {"name": "to", "type": "address"},
-        {"name": "fee_payer", "type": "address"},
+        {"name": "payee", "type": "address"},
         {"name": "amount", "type": "i128"},
-        {"name": "fee_amount", "type": "i128"},
-        {"name": "fee_rate", "type": "u32"}
+        {"name": "memo", "type": "string"},
+        {"name": "expires_at", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "pay_invoice",
+      "inputs": [
+        {"name": "invoice_id", "type": "u64"},
+        {"name": "payer", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "cancel_invoice",
+      "inputs": [
+        {"name": "invoice_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_invoice",
+      "inputs": [
+        {"name": "invoice_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "Invoice", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "escrow_payment",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "buyer", "type": "address"},
+        {"name": "seller", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "dispute_window", "type": "u64"},
+        {"name": "memo", "type": "option", "value": "string"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "confirm_escrow",
+      "inputs": [
+        {"name": "escrow_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "release_escrow",
+      "inputs": [
+        {"name": "escrow_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "dispute_escrow",
+      "inputs": [
+        {"name": "escrow_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "resolve_escrow_dispute",
+      "inputs": [
+        {"name": "escrow_id", "type": "u64"},
+        {"name": "refund_buyer", "type": "bool"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_escrow",
+      "inputs": [
+        {"name": "escrow_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "Escrow", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "create_subscription",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "payer", "type": "address"},
+        {"name": "payee", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "period", "type": "u64"},
+        {"name": "allowance", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "collect_due",
+      "inputs": [
+        {"name": "subscription_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "cancel_subscription",
+      "inputs": [
+        {"name": "subscription_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_subscription",
+      "inputs": [
+        {"name": "subscription_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "Subscription", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "issue_ticket",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "owner", "type": "address"},
+        {"name": "face_value", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "set_ticket_price",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "price", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_ticket_price",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "option", "value": "i128"}
+      ]
+    },
+    {
+      "name": "purchase_ticket",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "buyer", "type": "address"},
+        {"name": "code", "type": "option", "value": "bytes"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "purchase_ticket_with_max_price",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "buyer", "type": "address"},
+        {"name": "code", "type": "option", "value": "bytes"},
+        {"name": "max_price", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "set_resale_config",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "config", "type": "ResaleConfig"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_resale_config",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "ResaleConfig"}
+      ]
+    },
+    {
+      "name": "get_ticket",
+      "inputs": [
+        {"name": "ticket_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "Ticket", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "transfer_ticket",
+      "inputs": [
+        {"name": "ticket_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "list_ticket",
+      "inputs": [
+        {"name": "ticket_id", "type": "u64"},
+        {"name": "price", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "cancel_ticket_listing",
+      "inputs": [
+        {"name": "ticket_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "buy_ticket",
+      "inputs": [
+        {"name": "ticket_id", "type": "u64"},
+        {"name": "buyer", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "set_ticket_base_uri",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "base_uri", "type": "string"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "name",
+      "inputs": [],
+      "outputs": [
+        {"type": "string"}
+      ]
+    },
+    {
+      "name": "symbol",
+      "inputs": [],
+      "outputs": [
+        {"type": "string"}
+      ]
+    },
+    {
+      "name": "balance",
+      "inputs": [
+        {"name": "account", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "u32"}
+      ]
+    },
+    {
+      "name": "owner_of",
+      "inputs": [
+        {"name": "token_id", "type": "u32"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "address", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "token_uri",
+      "inputs": [
+        {"name": "token_id", "type": "u32"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "string", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "transfer",
+      "inputs": [
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "token_id", "type": "u32"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "open_bill",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "payee", "type": "address"},
+        {"name": "total", "type": "i128"},
+        {"name": "memo", "type": "option", "value": "string"},
+        {"name": "expires_at", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "contribute_to_bill",
+      "inputs": [
+        {"name": "bill_id", "type": "u64"},
+        {"name": "contributor", "type": "address"},
+        {"name": "amount", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "cancel_bill",
+      "inputs": [
+        {"name": "bill_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "i128", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "reclaim_bill_share",
+      "inputs": [
+        {"name": "bill_id", "type": "u64"},
+        {"name": "contributor", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "i128", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_bill",
+      "inputs": [
+        {"name": "bill_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "Bill", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_bill_contribution",
+      "inputs": [
+        {"name": "bill_id", "type": "u64"},
+        {"name": "contributor", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "i128"}
+      ]
+    },
+    {
+      "name": "register_artist",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "artist", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "is_artist",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "artist", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "bool"}
+      ]
+    },
+    {
+      "name": "set_tip_fee_rate",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "fee_rate", "type": "u32"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_tip_fee_rate",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "u32"}
+      ]
+    },
+    {
+      "name": "tip",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "artist", "type": "address"},
+        {"name": "amount", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_artist_tips",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "artist", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "i128"}
+      ]
+    },
+    {
+      "name": "get_tip_leaderboard",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "vec", "value": "ArtistTips"}
+      ]
+    },
+    {
+      "name": "set_loyalty_config",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "config", "type": "LoyaltyConfig"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_loyalty_config",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "LoyaltyConfig"}
+      ]
+    },
+    {
+      "name": "get_points",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "i128"}
+      ]
+    },
+    {
+      "name": "get_fee_credit",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "i128"}
+      ]
+    },
+    {
+      "name": "redeem_points",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"},
+        {"name": "points", "type": "i128"},
+        {"name": "reward", "type": "LoyaltyReward"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "i128", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "create_discount_code",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "code_hash", "type": "bytes32"},
+        {"name": "kind", "type": "DiscountKind"},
+        {"name": "max_uses", "type": "u32"},
+        {"name": "expires_at", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_discount_code",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "code_hash", "type": "bytes32"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "DiscountCode", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "event_payment_with_code",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "memo", "type": "option", "value": "string"},
+        {"name": "code", "type": "bytes"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "set_fiat_pricing",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "pricing", "type": "option", "value": "FiatPricing"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_fiat_pricing",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "option", "value": "FiatPricing"}
+      ]
+    },
+    {
+      "name": "quote_fiat",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "fiat_amount", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "i128", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "event_payment_fiat",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "fiat_amount", "type": "i128"},
+        {"name": "max_amount", "type": "i128"},
+        {"name": "memo", "type": "option", "value": "string"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "path_payment",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "route", "type": "SwapRoute"},
+        {"name": "memo", "type": "option", "value": "string"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "event_payment",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "memo", "type": "option", "value": "string"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "payment_with_third_party_fee",
+      "inputs": [
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "fee_payer", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "memo", "type": "option", "value": "string"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "payment_with_auth_fee_payer",
+      "inputs": [
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "fee_payer", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "memo", "type": "option", "value": "string"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "u64", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "set_event_fee_mode",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "mode", "type": "FeeMode"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_event_fee_mode",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "FeeMode"}
+      ]
+    },
+    {
+      "name": "authorize_fee_payments",
+      "inputs": [
+        {"name": "fee_payer", "type": "address"},
+        {"name": "max_fee_amount", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "revoke_fee_authorization",
+      "inputs": [
+        {"name": "fee_payer", "type": "address"}
+      ],
+      "outputs": []
+    },
+    {
+      "name": "get_fee_budget",
+      "inputs": [
+        {"name": "fee_payer", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "i128"}
+      ]
+    },
+    {
+      "name": "increase_event_allowance",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "additional_allowance", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "withdraw_event_fees",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "i128", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_liabilities",
+      "inputs": [],
+      "outputs": [
+        {"type": "i128"}
+      ]
+    },
+    {
+      "name": "check_solvency",
+      "inputs": [],
+      "outputs": [
+        {"type": "result", "ok": "Solvency", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "sweep",
+      "inputs": [
+        {"name": "token", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "amount", "type": "i128"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "set_active_withdrawal_limit",
+      "inputs": [
+        {"name": "admin", "type": "address"},
+        {"name": "limit_bps", "type": "u32"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_active_withdrawal_limit",
+      "inputs": [],
+      "outputs": [
+        {"type": "u32"}
+      ]
+    },
+    {
+      "name": "get_organizer_events",
+      "inputs": [
+        {"name": "organizer", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "vec", "value": "u64"}
+      ]
+    },
+    {
+      "name": "get_treasury",
+      "inputs": [
+        {"name": "organizer", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "OrganizerTreasury", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "withdraw_all_fees",
+      "inputs": [
+        {"name": "organizer", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "i128", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "set_payout_split",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "shares", "type": "vec", "value": "PayoutShare"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "void", "error": "ContractError"}
+      ]
+    },
+    {
+      "name": "get_payout_split",
+      "inputs": [
+        {"name": "event_id", "type": "u64"}
+      ],
+      "outputs": [
+        {"type": "vec", "value": "PayoutShare"}
+      ]
+    },
+    {
+      "name": "get_claimable_payout",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "recipient", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "i128"}
+      ]
+    },
+    {
+      "name": "claim_payout",
+      "inputs": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "recipient", "type": "address"}
+      ],
+      "outputs": [
+        {"type": "result", "ok": "i128", "error": "ContractError"}
+      ]
+    }
+  ],
+  "types": [
+    {
+      "name": "ContractError",
+      "type": "enum",
+      "cases": [
+        {"name": "FeeRateExceeds10Percent", "value": 1},
+        {"name": "AmountMustBePositive", "value": 2},
+        {"name": "ContractNotInitialized", "value": 3},
+        {"name": "InsufficientBalanceFromSender", "value": 4},
+        {"name": "InsufficientAllowance", "value": 5},
+        {"name": "EventNotFound", "value": 6},
+        {"name": "EventNotActive", "value": 7},
+        {"name": "NotEventOrganizer", "value": 8},
+        {"name": "EventNameTooLong", "value": 9},
+        {"name": "EventAlreadyExists", "value": 10},
+        {"name": "AlreadyInitialized", "value": 11},
+        {"name": "EventStillActive", "value": 12},
+        {"name": "WalletNotRegistered", "value": 13},
+        {"name": "WalletAlreadyRegistered", "value": 14},
+        {"name": "OrganizerCannotRegister", "value": 15},
+        {"name": "SpendingLimitExceeded", "value": 16},
+        {"name": "SessionGrantExceeded", "value": 17},
+        {"name": "RecordNotFound", "value": 18},
+        {"name": "Expired", "value": 19},
+        {"name": "TextTooLong", "value": 20},
+        {"name": "CapacityReached", "value": 21},
+        {"name": "InvalidState", "value": 22},
+        {"name": "NotYetDue", "value": 23},
+        {"name": "InvalidAmount", "value": 24},
+        {"name": "Unauthorized", "value": 25},
+        {"name": "TicketNotTransferable", "value": 26},
+        {"name": "ResalePriceAboveCap", "value": 27},
+        {"name": "ContributionExceedsRemaining", "value": 28},
+        {"name": "ArtistNotRegistered", "value": 29},
+        {"name": "InsufficientPoints", "value": 30},
+        {"name": "LoyaltyRedemptionDisabled", "value": 31},
+        {"name": "InsufficientEventFees", "value": 32},
+        {"name": "DiscountCodeExhausted", "value": 33},
+        {"name": "DiscountNotApplicable", "value": 34},
+        {"name": "SweepExceedsSurplus", "value": 35},
+        {"name": "ArithmeticOverflow", "value": 36},
+        {"name": "OraclePriceStale", "value": 37},
+        {"name": "SlippageExceeded", "value": 38},
+        {"name": "InvalidSignature", "value": 39},
+        {"name": "NonceAlreadyUsed", "value": 40}
+      ]
+    },
+    {
+      "name": "Event",
+      "type": "struct",
+      "fields": [
+        {"name": "created_at", "type": "u64"},
+        {"name": "fee_rate", "type": "u32"},
+        {"name": "id", "type": "u64"},
+        {"name": "is_active", "type": "bool"},
+        {"name": "name", "type": "string"},
+        {"name": "organizer", "type": "address"},
+        {"name": "total_volume", "type": "i128"}
+      ]
+    },
+    {
+      "name": "SpendingLimit",
+      "type": "struct",
+      "fields": [
+        {"name": "per_day", "type": "option", "value": "i128"},
+        {"name": "per_event", "type": "option", "value": "i128"},
+        {"name": "per_payment", "type": "option", "value": "i128"}
+      ]
+    },
+    {
+      "name": "WalletSpending",
+      "type": "struct",
+      "fields": [
+        {"name": "day", "type": "u64"},
+        {"name": "spent_today", "type": "i128"},
+        {"name": "spent_total", "type": "i128"}
+      ]
+    },
+    {
+      "name": "SessionGrant",
+      "type": "struct",
+      "fields": [
+        {"name": "amount_cap", "type": "i128"},
+        {"name": "delegate", "type": "address"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "expires_at", "type": "u64"},
+        {"name": "grantor", "type": "address"},
+        {"name": "spent", "type": "i128"}
+      ]
+    },
+    {
+      "name": "InvoiceStatus",
+      "type": "union",
+      "cases": [
+        {"name": "Open"},
+        {"name": "Paid"},
+        {"name": "Expired"},
+        {"name": "Cancelled"}
+      ]
+    },
+    {
+      "name": "Invoice",
+      "type": "struct",
+      "fields": [
+        {"name": "amount", "type": "i128"},
+        {"name": "created_at", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "expires_at", "type": "u64"},
+        {"name": "id", "type": "u64"},
+        {"name": "memo", "type": "string"},
+        {"name": "payee", "type": "address"},
+        {"name": "payer", "type": "option", "value": "address"},
+        {"name": "status", "type": "InvoiceStatus"}
+      ]
+    },
+    {
+      "name": "PaymentRecord",
+      "type": "struct",
+      "fields": [
+        {"name": "amount", "type": "i128"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "fee_amount", "type": "i128"},
+        {"name": "fee_payer", "type": "address"},
+        {"name": "fee_rate", "type": "u32"},
+        {"name": "from", "type": "address"},
+        {"name": "id", "type": "u64"},
+        {"name": "memo", "type": "option", "value": "string"},
+        {"name": "timestamp", "type": "u64"},
+        {"name": "to", "type": "address"}
+      ]
+    },
+    {
+      "name": "EscrowStatus",
+      "type": "union",
+      "cases": [
+        {"name": "Held"},
+        {"name": "Disputed"},
+        {"name": "Released"},
+        {"name": "Refunded"}
+      ]
+    },
+    {
+      "name": "Escrow",
+      "type": "struct",
+      "fields": [
+        {"name": "amount", "type": "i128"},
+        {"name": "buyer", "type": "address"},
+        {"name": "created_at", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "fee_amount", "type": "i128"},
+        {"name": "fee_mode", "type": "FeeMode"},
+        {"name": "fee_rate", "type": "u32"},
+        {"name": "id", "type": "u64"},
+        {"name": "memo", "type": "option", "value": "string"},
+        {"name": "release_at", "type": "u64"},
+        {"name": "seller", "type": "address"},
+        {"name": "status", "type": "EscrowStatus"}
+      ]
+    },
+    {
+      "name": "Subscription",
+      "type": "struct",
+      "fields": [
+        {"name": "amount", "type": "i128"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "id", "type": "u64"},
+        {"name": "is_active", "type": "bool"},
+        {"name": "next_due", "type": "u64"},
+        {"name": "payee", "type": "address"},
+        {"name": "payer", "type": "address"},
+        {"name": "payments_collected", "type": "u32"},
+        {"name": "period", "type": "u64"}
+      ]
+    },
+    {
+      "name": "Ticket",
+      "type": "struct",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "face_value", "type": "i128"},
+        {"name": "id", "type": "u64"},
+        {"name": "owner", "type": "address"},
+        {"name": "resale_price", "type": "option", "value": "i128"}
+      ]
+    },
+    {
+      "name": "ResaleConfig",
+      "type": "struct",
+      "fields": [
+        {"name": "max_markup_bps", "type": "u32"},
+        {"name": "royalty_bps", "type": "u32"},
+        {"name": "transferable", "type": "bool"}
+      ]
+    },
+    {
+      "name": "BillStatus",
+      "type": "union",
+      "cases": [
+        {"name": "Open"},
+        {"name": "Settled"},
+        {"name": "Cancelled"}
+      ]
+    },
+    {
+      "name": "Bill",
+      "type": "struct",
+      "fields": [
+        {"name": "contributors", "type": "vec", "value": "address"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "expires_at", "type": "u64"},
+        {"name": "funded", "type": "i128"},
+        {"name": "id", "type": "u64"},
+        {"name": "memo", "type": "option", "value": "string"},
+        {"name": "payee", "type": "address"},
+        {"name": "status", "type": "BillStatus"},
+        {"name": "total", "type": "i128"}
+      ]
+    },
+    {
+      "name": "ArtistTips",
+      "type": "struct",
+      "fields": [
+        {"name": "artist", "type": "address"},
+        {"name": "total", "type": "i128"}
+      ]
+    },
+    {
+      "name": "LoyaltyConfig",
+      "type": "struct",
+      "fields": [
+        {"name": "earn_rate_bps", "type": "u32"},
+        {"name": "point_value_bps", "type": "u32"}
+      ]
+    },
+    {
+      "name": "LoyaltyReward",
+      "type": "union",
+      "cases": [
+        {"name": "FeeDiscount"},
+        {"name": "Voucher"}
+      ]
+    },
+    {
+      "name": "DiscountKind",
+      "type": "union",
+      "cases": [
+        {"name": "TicketPercent", "values": ["u32"]},
+        {"name": "FeeWaiver"}
+      ]
+    },
+    {
+      "name": "DiscountCode",
+      "type": "struct",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "expires_at", "type": "u64"},
+        {"name": "kind", "type": "DiscountKind"},
+        {"name": "max_uses", "type": "u32"},
+        {"name": "uses", "type": "u32"}
+      ]
+    },
+    {
+      "name": "OrganizerTreasury",
+      "type": "struct",
+      "fields": [
+        {"name": "accumulated", "type": "i128"},
+        {"name": "event_count", "type": "u32"},
+        {"name": "withdrawable", "type": "i128"},
+        {"name": "withdrawn", "type": "i128"}
+      ]
+    },
+    {
+      "name": "PayoutShare",
+      "type": "struct",
+      "fields": [
+        {"name": "recipient", "type": "address"},
+        {"name": "share_bps", "type": "u32"}
+      ]
+    },
+    {
+      "name": "Solvency",
+      "type": "struct",
+      "fields": [
+        {"name": "balance", "type": "i128"},
+        {"name": "liabilities", "type": "i128"},
+        {"name": "solvent", "type": "bool"},
+        {"name": "surplus", "type": "i128"}
+      ]
+    },
+    {
+      "name": "FeeMode",
+      "type": "union",
+      "cases": [
+        {"name": "Sender"},
+        {"name": "Sponsor"},
+        {"name": "Recipient"}
+      ]
+    },
+    {
+      "name": "RoundingMode",
+      "type": "union",
+      "cases": [
+        {"name": "Floor"},
+        {"name": "Ceil"},
+        {"name": "HalfEven"}
+      ]
+    },
+    {
+      "name": "FeePolicy",
+      "type": "struct",
+      "fields": [
+        {"name": "min_fee", "type": "i128"},
+        {"name": "rounding", "type": "RoundingMode"}
+      ]
+    },
+    {
+      "name": "Asset",
+      "type": "union",
+      "cases": [
+        {"name": "Stellar", "values": ["address"]},
+        {"name": "Other", "values": ["symbol"]}
+      ]
+    },
+    {
+      "name": "PriceData",
+      "type": "struct",
+      "fields": [
+        {"name": "price", "type": "i128"},
+        {"name": "timestamp", "type": "u64"}
+      ]
+    },
+    {
+      "name": "SwapRoute",
+      "type": "struct",
+      "fields": [
+        {"name": "amount_in", "type": "i128"},
+        {"name": "min_out", "type": "i128"},
+        {"name": "send_token", "type": "address"},
+        {"name": "via", "type": "vec", "value": "address"}
+      ]
+    },
+    {
+      "name": "PaymentKey",
+      "type": "union",
+      "cases": [
+        {"name": "Ed25519", "values": ["bytes32"]},
+        {"name": "Secp256r1", "values": ["bytes65"]}
+      ]
+    },
+    {
+      "name": "PaymentIntent",
+      "type": "struct",
+      "fields": [
+        {"name": "amount", "type": "i128"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "expires_at", "type": "u64"},
+        {"name": "nonce", "type": "u64"},
+        {"name": "payee", "type": "address"},
+        {"name": "payer", "type": "address"}
+      ]
+    },
+    {
+      "name": "FiatPricing",
+      "type": "struct",
+      "fields": [
+        {"name": "currency", "type": "symbol"},
+        {"name": "max_price_age", "type": "u64"},
+        {"name": "oracle", "type": "address"}
+      ]
+    },
+    {
+      "name": "ContractConfig",
+      "type": "struct",
+      "fields": [
+        {"name": "admin", "type": "address"},
+        {"name": "default_fee_rate", "type": "u32"},
+        {"name": "next_event_id", "type": "u64"},
+        {"name": "token_address", "type": "address"}
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "EventCreated",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "name", "type": "string"},
+        {"name": "organizer", "type": "address"},
+        {"name": "fee_rate", "type": "u32"}
+      ]
+    },
+    {
+      "name": "PaymentEvent",
+      "fields": [
+        {"name": "payment_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "fee_payer", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "fee_amount", "type": "i128"},
+        {"name": "fee_rate", "type": "u32"},
+        {"name": "memo", "type": "option", "value": "string"}
+      ]
+    },
+    {
+      "name": "InvoiceCreated",
+      "fields": [
+        {"name": "invoice_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "payee", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "memo", "type": "string"},
+        {"name": "expires_at", "type": "u64"}
+      ]
+    },
+    {
+      "name": "InvoicePaid",
+      "fields": [
+        {"name": "invoice_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "payer", "type": "address"},
+        {"name": "payee", "type": "address"},
+        {"name": "amount", "type": "i128"}
+      ]
+    },
+    {
+      "name": "InvoiceCancelled",
+      "fields": [
+        {"name": "invoice_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"}
+      ]
+    },
+    {
+      "name": "EscrowCreated",
+      "fields": [
+        {"name": "escrow_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "buyer", "type": "address"},
+        {"name": "seller", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "release_at", "type": "u64"}
+      ]
+    },
+    {
+      "name": "EscrowDisputed",
+      "fields": [
+        {"name": "escrow_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"}
+      ]
+    },
+    {
+      "name": "EscrowReleased",
+      "fields": [
+        {"name": "escrow_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "payment_id", "type": "u64"}
+      ]
+    },
+    {
+      "name": "EscrowRefunded",
+      "fields": [
+        {"name": "escrow_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "amount", "type": "i128"}
+      ]
+    },
+    {
+      "name": "SubscriptionCreated",
+      "fields": [
+        {"name": "subscription_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "payer", "type": "address"},
+        {"name": "payee", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "period", "type": "u64"}
+      ]
+    },
+    {
+      "name": "SubscriptionCollected",
+      "fields": [
+        {"name": "subscription_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "payment_id", "type": "u64"},
+        {"name": "next_due", "type": "u64"}
+      ]
+    },
+    {
+      "name": "SubscriptionCancelled",
+      "fields": [
+        {"name": "subscription_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"}
+      ]
+    },
+    {
+      "name": "TicketIssued",
+      "fields": [
+        {"name": "ticket_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "owner", "type": "address"},
+        {"name": "face_value", "type": "i128"}
+      ]
+    },
+    {
+      "name": "TicketTransferred",
+      "fields": [
+        {"name": "ticket_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "to", "type": "address"}
+      ]
+    },
+    {
+      "name": "TicketListed",
+      "fields": [
+        {"name": "ticket_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "price", "type": "option", "value": "i128"}
+      ]
+    },
+    {
+      "name": "TicketSold",
+      "fields": [
+        {"name": "ticket_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "seller", "type": "address"},
+        {"name": "buyer", "type": "address"},
+        {"name": "price", "type": "i128"},
+        {"name": "royalty", "type": "i128"}
+      ]
+    },
+    {
+      "name": "BillOpened",
+      "fields": [
+        {"name": "bill_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "payee", "type": "address"},
+        {"name": "total", "type": "i128"}
+      ]
+    },
+    {
+      "name": "BillContribution",
+      "fields": [
+        {"name": "bill_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "contributor", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "funded", "type": "i128"}
+      ]
+    },
+    {
+      "name": "BillSettled",
+      "fields": [
+        {"name": "bill_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "payment_id", "type": "u64"}
+      ]
+    },
+    {
+      "name": "BillCancelled",
+      "fields": [
+        {"name": "bill_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "refunded", "type": "i128"}
+      ]
+    },
+    {
+      "name": "BillShareReclaimed",
+      "fields": [
+        {"name": "bill_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "contributor", "type": "address"},
+        {"name": "amount", "type": "i128"}
+      ]
+    },
+    {
+      "name": "ArtistRegistered",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "artist", "type": "address"}
+      ]
+    },
+    {
+      "name": "TipSent",
+      "fields": [
+        {"name": "payment_id", "type": "u64"},
+        {"name": "event_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "artist", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "artist_total", "type": "i128"}
+      ]
+    },
+    {
+      "name": "PointsEarned",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"},
+        {"name": "points", "type": "i128"},
+        {"name": "balance", "type": "i128"}
+      ]
+    },
+    {
+      "name": "PointsRedeemed",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"},
+        {"name": "points", "type": "i128"},
+        {"name": "reward", "type": "LoyaltyReward"},
+        {"name": "value", "type": "i128"}
+      ]
+    },
+    {
+      "name": "DiscountCodeCreated",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "code_hash", "type": "bytes32"},
+        {"name": "kind", "type": "DiscountKind"},
+        {"name": "max_uses", "type": "u32"},
+        {"name": "expires_at", "type": "u64"}
+      ]
+    },
+    {
+      "name": "DiscountCodeRedeemed",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "code_hash", "type": "bytes32"},
+        {"name": "wallet", "type": "address"},
+        {"name": "discount", "type": "i128"},
+        {"name": "uses", "type": "u32"}
+      ]
+    },
+    {
+      "name": "TreasuryWithdrawn",
+      "fields": [
+        {"name": "organizer", "type": "address"},
+        {"name": "amount", "type": "i128"},
+        {"name": "event_count", "type": "u32"}
+      ]
+    },
+    {
+      "name": "PayoutSplitSet",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "recipients", "type": "u32"}
+      ]
+    },
+    {
+      "name": "PayoutClaimed",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "recipient", "type": "address"},
+        {"name": "amount", "type": "i128"}
+      ]
+    },
+    {
+      "name": "TokensSwept",
+      "fields": [
+        {"name": "admin", "type": "address"},
+        {"name": "token", "type": "address"},
+        {"name": "to", "type": "address"},
+        {"name": "amount", "type": "i128"}
+      ]
+    },
+    {
+      "name": "ContractInitialized",
+      "fields": [
+        {"name": "admin", "type": "address"},
+        {"name": "token", "type": "address"},
+        {"name": "default_fee_rate", "type": "u32"}
+      ]
+    },
+    {
+      "name": "DefaultFeeRateUpdated",
+      "fields": [
+        {"name": "admin", "type": "address"},
+        {"name": "old_rate", "type": "u32"},
+        {"name": "new_rate", "type": "u32"}
+      ]
+    },
+    {
+      "name": "FeePolicyUpdated",
+      "fields": [
+        {"name": "admin", "type": "address"},
+        {"name": "rounding", "type": "RoundingMode"},
+        {"name": "min_fee", "type": "i128"}
+      ]
+    },
+    {
+      "name": "WithdrawalLimitUpdated",
+      "fields": [
+        {"name": "admin", "type": "address"},
+        {"name": "limit_bps", "type": "u32"}
+      ]
+    },
+    {
+      "name": "EventStatusChanged",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "is_active", "type": "bool"}
+      ]
+    },
+    {
+      "name": "WalletRegistered",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"}
+      ]
+    },
+    {
+      "name": "WalletUnregistered",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "address"}
+      ]
+    },
+    {
+      "name": "SpendingLimitSet",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "wallet", "type": "option", "value": "address"},
+        {"name": "set_by", "type": "address"},
+        {"name": "limit", "type": "SpendingLimit"}
+      ]
+    },
+    {
+      "name": "SessionGrantCreated",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "grantor", "type": "address"},
+        {"name": "delegate", "type": "address"},
+        {"name": "amount_cap", "type": "i128"},
+        {"name": "expires_at", "type": "u64"}
+      ]
+    },
+    {
+      "name": "SessionGrantRevoked",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "grantor", "type": "address"},
+        {"name": "delegate", "type": "address"}
+      ]
+    },
+    {
+      "name": "TicketPriceSet",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "price", "type": "i128"}
+      ]
+    },
+    {
+      "name": "ResaleConfigSet",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "config", "type": "ResaleConfig"}
+      ]
+    },
+    {
+      "name": "TicketBaseUriSet",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "base_uri", "type": "string"}
+      ]
+    },
+    {
+      "name": "TipFeeRateSet",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "fee_rate", "type": "u32"}
+      ]
+    },
+    {
+      "name": "LoyaltyConfigSet",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "config", "type": "LoyaltyConfig"}
+      ]
+    },
+    {
+      "name": "FeeModeSet",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "mode", "type": "FeeMode"}
+      ]
+    },
+    {
+      "name": "FeeAuthorizationSet",
+      "fields": [
+        {"name": "fee_payer", "type": "address"},
+        {"name": "max_fee_amount", "type": "i128"}
+      ]
+    },
+    {
+      "name": "EventAllowanceIncreased",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "organizer", "type": "address"},
+        {"name": "added", "type": "i128"},
+        {"name": "allowance", "type": "i128"}
+      ]
+    },
+    {
+      "name": "FeesWithdrawn",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "organizer", "type": "address"},
+        {"name": "amount", "type": "i128"}
+      ]
+    },
+    {
+      "name": "FiatPricingSet",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "pricing", "type": "option", "value": "FiatPricing"}
+      ]
+    },
+    {
+      "name": "FiatConverted",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "currency", "type": "symbol"},
+        {"name": "fiat_amount", "type": "i128"},
+        {"name": "amount", "type": "i128"}
+      ]
+    },
+    {
+      "name": "PaymentKeySet",
+      "fields": [
+        {"name": "wallet", "type": "address"},
+        {"name": "key", "type": "option", "value": "PaymentKey"}
+      ]
+    },
+    {
+      "name": "SignedPaymentSubmitted",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "payer", "type": "address"},
+        {"name": "nonce", "type": "u64"},
+        {"name": "payment_id", "type": "u64"}
+      ]
+    },
+    {
+      "name": "PaymentNonceCancelled",
+      "fields": [
+        {"name": "payer", "type": "address"},
+        {"name": "nonce", "type": "u64"}
+      ]
+    },
+    {
+      "name": "SwapRouterSet",
+      "fields": [
+        {"name": "admin", "type": "address"},
+        {"name": "router", "type": "address"}
+      ]
+    },
+    {
+      "name": "PathPaymentSwapped",
+      "fields": [
+        {"name": "event_id", "type": "u64"},
+        {"name": "from", "type": "address"},
+        {"name": "send_token", "type": "address"},
+        {"name": "amount_in", "type": "i128"},
+        {"name": "amount_out", "type": "i128"}
       ]
     }
   ]
@@ -0,0 +1,215 @@
+//! Decoding of the contract's `#[contractevent]` layouts from XDR.
+//!
+//! The first topic is the event name in snake case, followed by the fields
+//! marked `#[topic]`; the remaining fields are in the value, as a map keyed by
+//! field name.
+
+use stellar_xdr::curr::{Limits, ReadXdr, ScMap, ScVal};
+
+use crate::{IndexerError, RpcEvent};
+
+/// Events the indexer materializes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContractEvent {
+    EventCreated {
+        event_id: u64,
+        organizer: String,
+        name: String,
+        fee_rate: u32,
+    },
+    Payment {
+        payment_id: u64,
+        event_id: u64,
+        from: String,
+        to: String,
+        fee_payer: String,
+        amount: i128,
+        fee_amount: i128,
+        fee_rate: u32,
+        memo: Option<String>,
+    },
+    /// Fees paid out of an event (closed-event or treasury withdrawal)
+    FeesWithdrawn {
+        event_id: u64,
+        organizer: String,
+        amount: i128,
+    },
+    EscrowRefunded {
+        escrow_id: u64,
+        event_id: u64,
+        amount: i128,
+    },
+    BillCancelled {
+        bill_id: u64,
+        event_id: u64,
+        refunded: i128,
+    },
+    BillShareReclaimed {
+        bill_id: u64,
+        event_id: u64,
+        contributor: String,
+        amount: i128,
+    },
+    /// `reward` is the `LoyaltyReward` variant name (`Voucher` vouchers are paid from event fees)
+    PointsRedeemed {
+        event_id: u64,
+        wallet: String,
+        points: i128,
+        reward: String,
+        value: i128,
+    },
+}
+
+const TRACKED: [&str; 7] = [
+    "event_created",
+    "payment_event",
+    "fees_withdrawn",
+    "escrow_refunded",
+    "bill_cancelled",
+    "bill_share_reclaimed",
+    "points_redeemed",
+];
+
+/// Decode one RPC event; `Ok(None)` for contract events the indexer doesn't track
+pub fn decode(event: &RpcEvent) -> Result<Option<ContractEvent>, IndexerError> {
+    let fail = |reason: String| IndexerError::Decode { id: event.id.clone(), reason };
+
+    let topics = event.topic.iter()
+        .map(|t| ScVal::from_xdr_base64(t, Limits::none()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| fail(e.to_string()))?;
+    let name = match topics.first() {
+        Some(ScVal::Symbol(name)) => name.to_utf8_string_lossy(),
+        _ => return Ok(None),
+    };
+    if !TRACKED.contains(&name.as_str()) {
+        return Ok(None);
+    }
+
+    let value = ScVal::from_xdr_base64(&event.value, Limits::none()).map_err(|e| fail(e.to_string()))?;
+    let ScVal::Map(Some(data)) = value else {
+        return Err(fail("event data is not a map".to_string()));
+    };
+    let fields = Fields { data: &data, topics: &topics };
+
+    build(&name, &fields).map(Some).map_err(|e| match e {
+        IndexerError::Decode { reason, .. } => fail(reason),
+        other => other,
+    })
+}
+
+fn build(name: &str, fields: &Fields) -> Result<ContractEvent, IndexerError> {
+    Ok(match name {
+        "event_created" => ContractEvent::EventCreated {
+            event_id: fields.topic(1)?,
+            organizer: fields.topic_address(2)?,
+            name: fields.string("name")?,
+            fee_rate: fields.get("fee_rate")?,
+        },
+        "fees_withdrawn" => ContractEvent::FeesWithdrawn {
+            event_id: fields.topic(1)?,
+            organizer: fields.topic_address(2)?,
+            amount: fields.get("amount")?,
+        },
+        "escrow_refunded" => ContractEvent::EscrowRefunded {
+            escrow_id: fields.get("escrow_id")?,
+            event_id: fields.topic(1)?,
+            amount: fields.get("amount")?,
+        },
+        "bill_cancelled" => ContractEvent::BillCancelled {
+            bill_id: fields.get("bill_id")?,
+            event_id: fields.topic(1)?,
+            refunded: fields.get("refunded")?,
+        },
+        "bill_share_reclaimed" => ContractEvent::BillShareReclaimed {
+            bill_id: fields.get("bill_id")?,
+            event_id: fields.topic(1)?,
+            contributor: fields.address("contributor")?,
+            amount: fields.get("amount")?,
+        },
+        "points_redeemed" => ContractEvent::PointsRedeemed {
+            event_id: fields.topic(1)?,
+            wallet: fields.address("wallet")?,
+            points: fields.get("points")?,
+            reward: fields.variant("reward")?,
+            value: fields.get("value")?,
+        },
+        "payment_event" => ContractEvent::Payment {
+            payment_id: fields.get("payment_id")?,
+            event_id: fields.topic(1)?,
+            from: fields.topic_address(2)?,
+            to: fields.topic_address(3)?,
+            fee_payer: fields.address("fee_payer")?,
+            amount: fields.get("amount")?,
+            fee_amount: fields.get("fee_amount")?,
+            fee_rate: fields.get("fee_rate")?,
+            memo: match fields.field("memo")? {
+                ScVal::Void => None,
+                ScVal::String(memo) => Some(memo.to_utf8_string_lossy()),
+                _ => return Err(decode_error("field memo is not a string".to_string())),
+            },
+        },
+        // Only reachable if TRACKED lists a name without a layout here
+        other => return Err(decode_error(format!("no layout for event {other}"))),
+    })
+}
+
+struct Fields<'a> {
+    data: &'a ScMap,
+    topics: &'a [ScVal],
+}
+
+impl Fields<'_> {
+    fn field(&self, key: &str) -> Result<&ScVal, IndexerError> {
+        self.data.iter()
+            .find(|entry| matches!(&entry.key, ScVal::Symbol(s) if s.to_utf8_string_lossy() == key))
+            .map(|entry| &entry.val)
+            .ok_or_else(|| decode_error(format!("missing field {key}")))
+    }
+
+    fn get<T: TryFrom<ScVal>>(&self, key: &str) -> Result<T, IndexerError> {
+        T::try_from(self.field(key)?.clone()).map_err(|_| decode_error(format!("field {key} has the wrong type")))
+    }
+
+    fn string(&self, key: &str) -> Result<String, IndexerError> {
+        match self.field(key)? {
+            ScVal::String(s) => Ok(s.to_utf8_string_lossy()),
+            _ => Err(decode_error(format!("field {key} is not a string"))),
+        }
+    }
+
+    fn address(&self, key: &str) -> Result<String, IndexerError> {
+        match self.field(key)? {
+            ScVal::Address(address) => Ok(address.to_string()),
+            _ => Err(decode_error(format!("field {key} is not an address"))),
+        }
+    }
+
+    // Unit enum variants are encoded as a vec holding the variant name
+    fn variant(&self, key: &str) -> Result<String, IndexerError> {
+        match self.field(key)? {
+            ScVal::Vec(Some(items)) => match items.first() {
+                Some(ScVal::Symbol(variant)) => Ok(variant.to_utf8_string_lossy()),
+                _ => Err(decode_error(format!("field {key} is not an enum variant"))),
+            },
+            _ => Err(decode_error(format!("field {key} is not an enum variant"))),
+        }
+    }
+
+    fn topic<T: TryFrom<ScVal>>(&self, index: usize) -> Result<T, IndexerError> {
+        let topic = self.topics.get(index).ok_or_else(|| decode_error(format!("missing topic {index}")))?;
+        T::try_from(topic.clone()).map_err(|_| decode_error(format!("topic {index} has the wrong type")))
+    }
+
+    fn topic_address(&self, index: usize) -> Result<String, IndexerError> {
+        match self.topics.get(index) {
+            Some(ScVal::Address(address)) => Ok(address.to_string()),
+            _ => Err(decode_error(format!("topic {index} is not an address"))),
+        }
+    }
+}
+
+// The event id is filled in by `decode`
+fn decode_error(reason: String) -> IndexerError {
+    IndexerError::Decode { id: String::new(), reason }
+}
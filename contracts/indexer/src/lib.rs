@@ -0,0 +1,109 @@
+//! Local indexer for EventCoin contract events.
+//!
+//! Pulls contract events from a Soroban RPC `getEvents` endpoint (or from a
+//! recorded JSON fixture with the same shape), decodes `EventCreated` and
+//! `PaymentEvent`, and materializes them into a SQLite store that answers
+//! aggregate queries: volume per event and fees per organizer. Withdrawals,
+//! refunds and vouchers are indexed too, so per-event settlement reports can be
+//! exported and reconciled with the contract's own figures (see [`report`]).
+//!
+//! Syncing is resumable: the store keeps the RPC cursor of the last page, and
+//! re-ingesting the same events is a no-op.
+
+use std::fmt;
+
+pub mod decode;
+pub mod report;
+pub mod server;
+pub mod source;
+pub mod store;
+
+pub use decode::{decode, ContractEvent};
+pub use report::{settlement_report, Discrepancy, OnChainEvent, SettlementReport, SettlementRow, SettlementTotals};
+pub use source::{EventPage, EventSource, Fixture, RpcClient, RpcEvent, StartAt};
+pub use store::{EventVolume, OrganizerFees, Store};
+
+/// Errors raised while fetching, decoding or storing events
+#[derive(Debug)]
+pub enum IndexerError {
+    /// The RPC endpoint could not be reached or returned an error
+    Rpc(String),
+    /// A fixture or RPC payload is not valid JSON in the `getEvents` shape
+    Format(String),
+    /// An event's topics or value don't match the contract's event layout
+    Decode { id: String, reason: String },
+    /// SQLite failure
+    Store(rusqlite::Error),
+}
+
+impl fmt::Display for IndexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexerError::Rpc(reason) => write!(f, "rpc error: {reason}"),
+            IndexerError::Format(reason) => write!(f, "invalid events payload: {reason}"),
+            IndexerError::Decode { id, reason } => write!(f, "could not decode event {id}: {reason}"),
+            IndexerError::Store(e) => write!(f, "store error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for IndexerError {}
+
+impl From<rusqlite::Error> for IndexerError {
+    fn from(e: rusqlite::Error) -> Self {
+        IndexerError::Store(e)
+    }
+}
+
+/// Counters of a sync run
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SyncStats {
+    pub pages: u32,
+    pub fetched: u32,
+    /// Events decoded and stored (duplicates are counted once)
+    pub indexed: u32,
+    /// Contract events of other kinds, ignored by the indexer
+    pub skipped: u32,
+}
+
+/// Fetch pages from `source` until it runs dry, storing every known event.
+/// Starts from the stored cursor when there is one, otherwise from `start_ledger`.
+pub fn sync(source: &mut dyn EventSource, store: &mut Store, start_ledger: u32) -> Result<SyncStats, IndexerError> {
+    let mut stats = SyncStats::default();
+    let mut start = match store.cursor()? {
+        Some(cursor) => StartAt::Cursor(cursor),
+        None => StartAt::Ledger(start_ledger),
+    };
+
+    loop {
+        let page = source.fetch(&start)?;
+        if page.events.is_empty() {
+            break;
+        }
+        stats.pages += 1;
+        stats.fetched += page.events.len() as u32;
+
+        let mut decoded = Vec::new();
+        for event in &page.events {
+            match decode(event)? {
+                Some(contract_event) => decoded.push((event, contract_event)),
+                None => stats.skipped += 1,
+            }
+        }
+
+        let cursor = page.cursor.or_else(|| page.events.last().map(|e| e.id.clone()));
+        stats.indexed += store.ingest(&decoded, cursor.as_deref())?;
+
+        match cursor {
+            Some(cursor) => start = StartAt::Cursor(cursor),
+            None => break,
+        }
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod test_indexer;
+#[cfg(test)]
+mod test_report;
@@ -0,0 +1,121 @@
+//! Event indexer CLI
+//!
+//! Usage:
+//!   event-indexer sync --rpc <url> --contract <contract-id> [--start-ledger <n>] [--db <file>]
+//!   event-indexer load <fixture.json> [--db <file>]
+//!   event-indexer query <volume|fees> [--db <file>]
+//!   event-indexer report [--format csv|json] [--event <id>] [--state <on-chain.json>] [--db <file>]
+//!   event-indexer serve [--addr <host:port>] [--db <file>]
+
+use std::{env, path::PathBuf, process};
+
+use event_indexer::{server, settlement_report, sync, EventSource, Fixture, OnChainEvent, RpcClient, Store};
+
+const USAGE: &str = "usage: event-indexer <sync|load|query|report|serve> [args] [--db <file>]";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let Some(command) = args.next() else {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    };
+
+    let mut db = PathBuf::from("indexer.db");
+    let mut rpc = None;
+    let mut contract = None;
+    let mut start_ledger = 0;
+    let mut addr = "127.0.0.1:8080".to_string();
+    let mut format = "csv".to_string();
+    let mut event_id = None;
+    let mut state = None;
+    let mut positional = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--db" => db = args.next().map(PathBuf::from).unwrap_or(db),
+            "--rpc" => rpc = args.next(),
+            "--contract" => contract = args.next(),
+            "--start-ledger" => start_ledger = args.next().and_then(|n| n.parse().ok()).unwrap_or(0),
+            "--addr" => addr = args.next().unwrap_or(addr),
+            "--format" => format = args.next().unwrap_or(format),
+            "--event" => event_id = args.next().and_then(|id| id.parse().ok()),
+            "--state" => state = args.next().map(PathBuf::from),
+            _ => positional = Some(arg),
+        }
+    }
+
+    let mut store = Store::open(&db).unwrap_or_else(|e| fail(&e));
+
+    match command.as_str() {
+        "sync" | "load" => {
+            let mut source: Box<dyn EventSource> = match (command.as_str(), rpc, contract, positional) {
+                ("sync", Some(rpc), Some(contract), _) => Box::new(RpcClient::new(&rpc, &contract)),
+                ("load", _, _, Some(path)) => Box::new(Fixture::from_file(&PathBuf::from(path)).unwrap_or_else(|e| fail(&e))),
+                _ => {
+                    eprintln!("{USAGE}");
+                    process::exit(2);
+                }
+            };
+            let stats = sync(source.as_mut(), &mut store, start_ledger).unwrap_or_else(|e| fail(&e));
+            println!(
+                "✅ {} events fetched in {} pages: {} indexed, {} skipped",
+                stats.fetched, stats.pages, stats.indexed, stats.skipped
+            );
+        }
+        "query" => {
+            let json = match positional.as_deref() {
+                Some("volume") => serde_json::to_string_pretty(&store.volume_per_event().unwrap_or_else(|e| fail(&e))),
+                Some("fees") => serde_json::to_string_pretty(&store.fees_per_organizer().unwrap_or_else(|e| fail(&e))),
+                _ => {
+                    eprintln!("usage: event-indexer query <volume|fees> [--db <file>]");
+                    process::exit(2);
+                }
+            };
+            println!("{}", json.unwrap());
+        }
+        "report" => {
+            let report = settlement_report(&store, event_id).unwrap_or_else(|e| fail(&e));
+            match format.as_str() {
+                "csv" => print!("{}", report.to_csv()),
+                "json" => println!("{}", report.to_json()),
+                _ => {
+                    eprintln!("usage: event-indexer report [--format csv|json] [--event <id>] [--state <on-chain.json>] [--db <file>]");
+                    process::exit(2);
+                }
+            }
+
+            // On-chain figures: [{ "event_id", "total_volume", "event_fees" }] from get_event and get_event_fees
+            if let Some(path) = state {
+                let json = std::fs::read_to_string(&path).unwrap_or_else(|e| fail(&e));
+                let mut on_chain: Vec<OnChainEvent> = serde_json::from_str(&json).unwrap_or_else(|e| fail(&e));
+                if let Some(event_id) = event_id {
+                    on_chain.retain(|e| e.event_id == event_id);
+                }
+                let discrepancies = report.reconcile(&on_chain);
+                if !discrepancies.is_empty() {
+                    for d in &discrepancies {
+                        eprintln!("⚠️  event {}: {} indexed {} on-chain {}", d.event_id, d.field, d.indexed, d.on_chain);
+                    }
+                    fail(&format!("{} discrepancies with on-chain state", discrepancies.len()));
+                }
+                eprintln!("✅ Report reconciles with on-chain state of {} events", on_chain.len());
+            }
+        }
+        "serve" => {
+            let http = tiny_http::Server::http(&addr).unwrap_or_else(|e| {
+                eprintln!("❌ Could not listen on {addr}: {e}");
+                process::exit(1);
+            });
+            println!("🚀 Serving {} on http://{addr}", db.display());
+            server::serve(&http, &store).unwrap_or_else(|e| fail(&e));
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    }
+}
+
+fn fail(error: &dyn std::fmt::Display) -> ! {
+    eprintln!("❌ {error}");
+    process::exit(1);
+}
@@ -0,0 +1,200 @@
+//! Per-event settlement reports built from indexed events, exported as CSV or
+//! JSON, and their reconciliation against on-chain state.
+//!
+//! Definitions (all amounts in token units):
+//! - `gross_volume`: sum of the event's payments; matches `Event.total_volume`
+//! - `fees`: fees charged on those payments (loyalty discounts already deducted)
+//! - `net_to_vendors`: what recipients received (`amount - fee` when the recipient pays the fee)
+//! - `withdrawals`: fees withdrawn by the organizer, including payout-split credits
+//! - `vouchers`: loyalty vouchers paid out of the event's fees
+//! - `refunds`: escrow refunds, cancelled split bills and reclaimed bill shares returned to payers
+//! - `outstanding_fees`: `fees - withdrawals - vouchers`; matches `get_event_fees`
+
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{IndexerError, Store};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SettlementRow {
+    pub event_id: u64,
+    pub name: String,
+    pub organizer: String,
+    pub payments: u64,
+    pub gross_volume: i64,
+    pub fees: i64,
+    pub net_to_vendors: i64,
+    pub withdrawals: i64,
+    pub vouchers: i64,
+    pub refunds: i64,
+    pub outstanding_fees: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SettlementTotals {
+    pub events: u64,
+    pub payments: u64,
+    pub gross_volume: i64,
+    pub fees: i64,
+    pub net_to_vendors: i64,
+    pub withdrawals: i64,
+    pub vouchers: i64,
+    pub refunds: i64,
+    pub outstanding_fees: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SettlementReport {
+    pub events: Vec<SettlementRow>,
+    pub totals: SettlementTotals,
+}
+
+/// On-chain figures of an event, as returned by `get_event` and `get_event_fees`
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct OnChainEvent {
+    pub event_id: u64,
+    pub total_volume: i128,
+    pub event_fees: i128,
+}
+
+/// A figure of the report that doesn't match the chain
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Discrepancy {
+    pub event_id: u64,
+    pub field: &'static str,
+    pub indexed: i128,
+    pub on_chain: i128,
+}
+
+const CSV_HEADER: &str =
+    "event_id,name,organizer,payments,gross_volume,fees,net_to_vendors,withdrawals,vouchers,refunds,outstanding_fees";
+
+/// Build the report of every indexed event (or just `event_id`), ordered by event id
+pub fn settlement_report(store: &Store, event_id: Option<u64>) -> Result<SettlementReport, IndexerError> {
+    let mut stmt = store.connection().prepare(
+        "SELECT e.event_id, e.name, e.organizer,
+                COALESCE(p.payments, 0), COALESCE(p.volume, 0), COALESCE(p.fees, 0), COALESCE(p.net, 0),
+                COALESCE(a.withdrawals, 0), COALESCE(a.vouchers, 0), COALESCE(a.refunds, 0)
+         FROM events e
+         LEFT JOIN (
+             SELECT event_id, COUNT(*) AS payments, SUM(amount) AS volume, SUM(fee_amount) AS fees,
+                    SUM(CASE WHEN fee_payer = to_addr THEN amount - fee_amount ELSE amount END) AS net
+             FROM payments GROUP BY event_id
+         ) p ON p.event_id = e.event_id
+         LEFT JOIN (
+             SELECT event_id,
+                    SUM(CASE WHEN kind = 'withdrawal' THEN amount ELSE 0 END) AS withdrawals,
+                    SUM(CASE WHEN kind = 'voucher' THEN amount ELSE 0 END) AS vouchers,
+                    SUM(CASE WHEN kind = 'refund' THEN amount ELSE 0 END) AS refunds
+             FROM adjustments GROUP BY event_id
+         ) a ON a.event_id = e.event_id
+         WHERE ?1 IS NULL OR e.event_id = ?1
+         ORDER BY e.event_id",
+    )?;
+    let rows = stmt.query_map([event_id.map(|id| id as i64)], |row| {
+        let fees: i64 = row.get(5)?;
+        let withdrawals: i64 = row.get(7)?;
+        let vouchers: i64 = row.get(8)?;
+        Ok(SettlementRow {
+            event_id: row.get::<_, i64>(0)? as u64,
+            name: row.get(1)?,
+            organizer: row.get(2)?,
+            payments: row.get::<_, i64>(3)? as u64,
+            gross_volume: row.get(4)?,
+            fees,
+            net_to_vendors: row.get(6)?,
+            withdrawals,
+            vouchers,
+            refunds: row.get(9)?,
+            outstanding_fees: fees - withdrawals - vouchers,
+        })
+    })?;
+    let events: Vec<SettlementRow> = rows.collect::<Result<_, _>>()?;
+
+    let mut totals = SettlementTotals { events: events.len() as u64, ..Default::default() };
+    for row in &events {
+        totals.payments += row.payments;
+        totals.gross_volume += row.gross_volume;
+        totals.fees += row.fees;
+        totals.net_to_vendors += row.net_to_vendors;
+        totals.withdrawals += row.withdrawals;
+        totals.vouchers += row.vouchers;
+        totals.refunds += row.refunds;
+        totals.outstanding_fees += row.outstanding_fees;
+    }
+
+    Ok(SettlementReport { events, totals })
+}
+
+impl SettlementReport {
+    /// One line per event plus a final `TOTAL` line
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+        for r in &self.events {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                r.event_id, csv_field(&r.name), r.organizer, r.payments, r.gross_volume, r.fees,
+                r.net_to_vendors, r.withdrawals, r.vouchers, r.refunds, r.outstanding_fees
+            );
+        }
+        let t = &self.totals;
+        let _ = writeln!(
+            csv,
+            "TOTAL,,,{},{},{},{},{},{},{},{}",
+            t.payments, t.gross_volume, t.fees, t.net_to_vendors, t.withdrawals, t.vouchers, t.refunds, t.outstanding_fees
+        );
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Compare volume and outstanding fees with the chain; events missing on either side are reported too
+    pub fn reconcile(&self, on_chain: &[OnChainEvent]) -> Vec<Discrepancy> {
+        let mut discrepancies = Vec::new();
+
+        for chain in on_chain {
+            let row = self.events.iter().find(|r| r.event_id == chain.event_id);
+            let (volume, fees) = row.map_or((0, 0), |r| (r.gross_volume as i128, r.outstanding_fees as i128));
+            if row.is_none() {
+                discrepancies.push(Discrepancy { event_id: chain.event_id, field: "event", indexed: 0, on_chain: 1 });
+            }
+            if volume != chain.total_volume {
+                discrepancies.push(Discrepancy {
+                    event_id: chain.event_id,
+                    field: "gross_volume",
+                    indexed: volume,
+                    on_chain: chain.total_volume,
+                });
+            }
+            if fees != chain.event_fees {
+                discrepancies.push(Discrepancy {
+                    event_id: chain.event_id,
+                    field: "outstanding_fees",
+                    indexed: fees,
+                    on_chain: chain.event_fees,
+                });
+            }
+        }
+        for row in &self.events {
+            if !on_chain.iter().any(|c| c.event_id == row.event_id) {
+                discrepancies.push(Discrepancy { event_id: row.event_id, field: "event", indexed: 1, on_chain: 0 });
+            }
+        }
+
+        discrepancies
+    }
+}
+
+// Event names are free text: quote them when they contain separators or quotes
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
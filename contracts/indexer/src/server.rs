@@ -0,0 +1,47 @@
+//! Read-only HTTP API over the store.
+//!
+//! - `GET /events/volume` - `EventVolume` per event
+//! - `GET /organizers/fees` - `OrganizerFees` per organizer
+
+use tiny_http::{Header, Request, Response, Server};
+
+use crate::{IndexerError, Store};
+
+/// Answer one request from the store: (status, JSON body)
+pub fn route(store: &Store, method: &str, url: &str) -> (u16, String) {
+    if method != "GET" {
+        return (405, error_body("only GET is supported"));
+    }
+
+    let result = match url.split('?').next().unwrap_or("") {
+        "/events/volume" => store.volume_per_event().map(|rows| serde_json::to_string(&rows)),
+        "/organizers/fees" => store.fees_per_organizer().map(|rows| serde_json::to_string(&rows)),
+        _ => return (404, error_body("unknown route")),
+    };
+
+    match result {
+        Ok(Ok(body)) => (200, body),
+        Ok(Err(e)) => (500, error_body(&e.to_string())),
+        Err(e) => (500, error_body(&e.to_string())),
+    }
+}
+
+/// Serve requests until the server is shut down
+pub fn serve(server: &Server, store: &Store) -> Result<(), IndexerError> {
+    for request in server.incoming_requests() {
+        respond(store, request)?;
+    }
+    Ok(())
+}
+
+fn respond(store: &Store, request: Request) -> Result<(), IndexerError> {
+    let (status, body) = route(store, request.method().as_str(), request.url());
+    let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+    request
+        .respond(Response::from_string(body).with_status_code(status).with_header(header))
+        .map_err(|e| IndexerError::Rpc(e.to_string()))
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
@@ -0,0 +1,159 @@
+//! Event sources: a Soroban RPC endpoint or a recorded fixture.
+
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::IndexerError;
+
+/// One contract event as returned by `getEvents` (topics and value are base64 XDR `ScVal`s)
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcEvent {
+    #[serde(rename = "type", default = "contract_type")]
+    pub kind: String,
+    pub ledger: u32,
+    #[serde(default)]
+    pub ledger_closed_at: String,
+    pub contract_id: String,
+    /// Unique event id, also usable as a paging cursor
+    pub id: String,
+    #[serde(default)]
+    pub tx_hash: String,
+    pub topic: Vec<String>,
+    pub value: String,
+}
+
+fn contract_type() -> String {
+    "contract".to_string()
+}
+
+/// The `result` object of a `getEvents` response
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventPage {
+    pub events: Vec<RpcEvent>,
+    #[serde(default)]
+    pub latest_ledger: u32,
+    /// Cursor to continue after this page (newer RPC versions)
+    #[serde(default)]
+    pub cursor: Option<String>,
+}
+
+/// Where a fetch starts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StartAt {
+    Ledger(u32),
+    /// Continue after the event with this paging id
+    Cursor(String),
+}
+
+pub trait EventSource {
+    fn fetch(&mut self, start: &StartAt) -> Result<EventPage, IndexerError>;
+}
+
+/// JSON-RPC client for `getEvents`, filtered to one contract
+pub struct RpcClient {
+    url: String,
+    contract_id: String,
+    limit: u32,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    pub fn new(url: &str, contract_id: &str) -> Self {
+        RpcClient {
+            url: url.to_string(),
+            contract_id: contract_id.to_string(),
+            limit: 100,
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    /// Page size requested from the RPC (max 10000 on most providers)
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    fn request(&self, start: &StartAt) -> serde_json::Value {
+        let mut pagination = json!({ "limit": self.limit });
+        let mut params = json!({
+            "filters": [{ "type": "contract", "contractIds": [self.contract_id] }],
+        });
+        // startLedger and cursor are mutually exclusive
+        match start {
+            StartAt::Ledger(ledger) => params["startLedger"] = json!(ledger),
+            StartAt::Cursor(cursor) => pagination["cursor"] = json!(cursor),
+        }
+        params["pagination"] = pagination;
+
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "getEvents", "params": params })
+    }
+}
+
+impl EventSource for RpcClient {
+    fn fetch(&mut self, start: &StartAt) -> Result<EventPage, IndexerError> {
+        let response: serde_json::Value = self.agent
+            .post(&self.url)
+            .send_json(self.request(start))
+            .map_err(|e| IndexerError::Rpc(e.to_string()))?
+            .into_json()
+            .map_err(|e| IndexerError::Format(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(IndexerError::Rpc(error.to_string()));
+        }
+        let result = response.get("result").cloned()
+            .ok_or_else(|| IndexerError::Format("response has no result".to_string()))?;
+        serde_json::from_value(result).map_err(|e| IndexerError::Format(e.to_string()))
+    }
+}
+
+/// Events recorded to a file: a `getEvents` result object or a bare array of events, either
+/// one possibly still wrapped in the JSON-RPC response's `result`.
+/// Served in one page; fetching after its last event returns nothing.
+pub struct Fixture {
+    events: Vec<RpcEvent>,
+}
+
+impl Fixture {
+    pub fn from_json(json: &str) -> Result<Self, IndexerError> {
+        let value: serde_json::Value = serde_json::from_str(json)
+            .map_err(|e| IndexerError::Format(e.to_string()))?;
+        let events = match value.get("result").unwrap_or(&value) {
+            events @ serde_json::Value::Array(_) => serde_json::from_value(events.clone()),
+            page => serde_json::from_value::<EventPage>(page.clone()).map(|page| page.events),
+        }
+        .map_err(|e| IndexerError::Format(e.to_string()))?;
+        Ok(Fixture { events })
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, IndexerError> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| IndexerError::Format(format!("{}: {e}", path.display())))?;
+        Self::from_json(&json)
+    }
+
+    pub fn new(events: Vec<RpcEvent>) -> Self {
+        Fixture { events }
+    }
+}
+
+impl EventSource for Fixture {
+    fn fetch(&mut self, start: &StartAt) -> Result<EventPage, IndexerError> {
+        let events = match start {
+            StartAt::Ledger(ledger) => self.events.iter().filter(|e| e.ledger >= *ledger).cloned().collect(),
+            StartAt::Cursor(cursor) => match self.events.iter().position(|e| &e.id == cursor) {
+                Some(i) => self.events[i + 1..].to_vec(),
+                None => Vec::new(),
+            },
+        };
+        Ok(EventPage {
+            latest_ledger: self.events.iter().map(|e| e.ledger).max().unwrap_or(0),
+            cursor: None,
+            events,
+        })
+    }
+}
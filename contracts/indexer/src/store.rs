@@ -0,0 +1,205 @@
+//! SQLite store for indexed events and the aggregate queries served from it.
+
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+
+use crate::{ContractEvent, IndexerError, RpcEvent};
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS events (
+        event_id   INTEGER PRIMARY KEY,
+        name       TEXT NOT NULL,
+        organizer  TEXT NOT NULL,
+        fee_rate   INTEGER NOT NULL,
+        ledger     INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS payments (
+        payment_id  INTEGER PRIMARY KEY,
+        event_id    INTEGER NOT NULL,
+        from_addr   TEXT NOT NULL,
+        to_addr     TEXT NOT NULL,
+        fee_payer   TEXT NOT NULL,
+        amount      INTEGER NOT NULL,
+        fee_amount  INTEGER NOT NULL,
+        fee_rate    INTEGER NOT NULL,
+        memo        TEXT,
+        ledger      INTEGER NOT NULL,
+        tx_hash     TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS payments_by_event ON payments (event_id);
+    -- Money leaving an event outside payments: withdrawal, refund or voucher
+    CREATE TABLE IF NOT EXISTS adjustments (
+        rpc_id    TEXT PRIMARY KEY,
+        event_id  INTEGER NOT NULL,
+        kind      TEXT NOT NULL,
+        amount    INTEGER NOT NULL,
+        ledger    INTEGER NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS adjustments_by_event ON adjustments (event_id, kind);
+    CREATE TABLE IF NOT EXISTS sync_state (
+        key   TEXT PRIMARY KEY,
+        value TEXT NOT NULL
+    );
+";
+
+/// Payments and fees of one event
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct EventVolume {
+    pub event_id: u64,
+    pub name: String,
+    pub organizer: String,
+    pub payments: u64,
+    pub volume: i64,
+    pub fees: i64,
+}
+
+/// Fees charged across all events of an organizer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct OrganizerFees {
+    pub organizer: String,
+    pub events: u64,
+    pub payments: u64,
+    pub fees: i64,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: &Path) -> Result<Self, IndexerError> {
+        Self::init(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, IndexerError> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self, IndexerError> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Store { conn })
+    }
+
+    /// Paging cursor of the last ingested page
+    pub fn cursor(&self) -> Result<Option<String>, IndexerError> {
+        Ok(self.conn
+            .query_row("SELECT value FROM sync_state WHERE key = 'cursor'", [], |row| row.get(0))
+            .optional()?)
+    }
+
+    /// Store a page of decoded events and its cursor atomically.
+    /// Returns how many events were new (already indexed ones are ignored).
+    pub fn ingest(&mut self, events: &[(&RpcEvent, ContractEvent)], cursor: Option<&str>) -> Result<u32, IndexerError> {
+        let tx = self.conn.transaction()?;
+        let mut inserted = 0;
+
+        for (raw, event) in events {
+            inserted += match event {
+                ContractEvent::EventCreated { event_id, organizer, name, fee_rate } => tx.execute(
+                    "INSERT OR IGNORE INTO events (event_id, name, organizer, fee_rate, ledger)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![*event_id as i64, name, organizer, fee_rate, raw.ledger],
+                )?,
+                ContractEvent::Payment { payment_id, event_id, from, to, fee_payer, amount, fee_amount, fee_rate, memo } => tx.execute(
+                    "INSERT OR IGNORE INTO payments
+                     (payment_id, event_id, from_addr, to_addr, fee_payer, amount, fee_amount, fee_rate, memo, ledger, tx_hash)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                    params![
+                        *payment_id as i64,
+                        *event_id as i64,
+                        from,
+                        to,
+                        fee_payer,
+                        to_sql_amount(raw, *amount)?,
+                        to_sql_amount(raw, *fee_amount)?,
+                        fee_rate,
+                        memo,
+                        raw.ledger,
+                        raw.tx_hash,
+                    ],
+                )?,
+                ContractEvent::FeesWithdrawn { event_id, amount, .. } => Self::adjust(&tx, raw, *event_id, "withdrawal", *amount)?,
+                ContractEvent::EscrowRefunded { event_id, amount, .. } => Self::adjust(&tx, raw, *event_id, "refund", *amount)?,
+                ContractEvent::BillCancelled { event_id, refunded, .. } => Self::adjust(&tx, raw, *event_id, "refund", *refunded)?,
+                ContractEvent::BillShareReclaimed { event_id, amount, .. } => Self::adjust(&tx, raw, *event_id, "refund", *amount)?,
+                // Fee discounts are credited to the wallet, only vouchers leave the event's fees
+                ContractEvent::PointsRedeemed { event_id, reward, value, .. } if reward == "Voucher" => {
+                    Self::adjust(&tx, raw, *event_id, "voucher", *value)?
+                }
+                ContractEvent::PointsRedeemed { .. } => 0,
+            } as u32;
+        }
+
+        if let Some(cursor) = cursor {
+            tx.execute(
+                "INSERT INTO sync_state (key, value) VALUES ('cursor', ?1)
+                 ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+                params![cursor],
+            )?;
+        }
+        tx.commit()?;
+        Ok(inserted)
+    }
+
+    fn adjust(tx: &rusqlite::Transaction, raw: &RpcEvent, event_id: u64, kind: &str, amount: i128) -> Result<usize, IndexerError> {
+        Ok(tx.execute(
+            "INSERT OR IGNORE INTO adjustments (rpc_id, event_id, kind, amount, ledger) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![raw.id, event_id as i64, kind, to_sql_amount(raw, amount)?, raw.ledger],
+        )?)
+    }
+
+    /// Gross volume and fees of every indexed event
+    pub fn volume_per_event(&self) -> Result<Vec<EventVolume>, IndexerError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT e.event_id, e.name, e.organizer, COUNT(p.payment_id),
+                    COALESCE(SUM(p.amount), 0), COALESCE(SUM(p.fee_amount), 0)
+             FROM events e LEFT JOIN payments p ON p.event_id = e.event_id
+             GROUP BY e.event_id
+             ORDER BY e.event_id",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(EventVolume {
+                event_id: row.get::<_, i64>(0)? as u64,
+                name: row.get(1)?,
+                organizer: row.get(2)?,
+                payments: row.get::<_, i64>(3)? as u64,
+                volume: row.get(4)?,
+                fees: row.get(5)?,
+            })
+        })?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    /// Fees charged on the payments of each organizer's events, highest first
+    pub fn fees_per_organizer(&self) -> Result<Vec<OrganizerFees>, IndexerError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT e.organizer, COUNT(DISTINCT e.event_id), COUNT(p.payment_id), COALESCE(SUM(p.fee_amount), 0) AS fees
+             FROM events e LEFT JOIN payments p ON p.event_id = e.event_id
+             GROUP BY e.organizer
+             ORDER BY fees DESC, e.organizer",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(OrganizerFees {
+                organizer: row.get(0)?,
+                events: row.get::<_, i64>(1)? as u64,
+                payments: row.get::<_, i64>(2)? as u64,
+                fees: row.get(3)?,
+            })
+        })?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+}
+
+// SQLite integers are 64-bit; token amounts beyond that can't be aggregated in SQL
+fn to_sql_amount(raw: &RpcEvent, amount: i128) -> Result<i64, IndexerError> {
+    i64::try_from(amount).map_err(|_| IndexerError::Decode {
+        id: raw.id.clone(),
+        reason: format!("amount {amount} doesn't fit the store"),
+    })
+}
@@ -0,0 +1,369 @@
+// Events are produced by the real contract in the soroban test Env and encoded
+// exactly like `getEvents` returns them
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use payment_with_fee::{EventPaymentContract, EventPaymentContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _},
+    token::StellarAssetClient,
+    xdr::{Limits, ScAddress, ScVal, WriteXdr},
+    Address, Env, String as SorobanString, TryFromVal,
+};
+
+use crate::{
+    decode, server, sync, ContractEvent, EventPage, EventSource, Fixture, IndexerError, RpcClient, RpcEvent, StartAt, Store,
+};
+
+const RECORDED: &str = include_str!("../fixtures/events.json");
+
+/// Runs contract calls and records their events as RPC events
+pub(crate) struct Recorder<'a> {
+    pub(crate) env: Env,
+    pub(crate) client: EventPaymentContractClient<'a>,
+    pub(crate) token: StellarAssetClient<'a>,
+    pub(crate) admin: Address,
+    pub(crate) events: Vec<RpcEvent>,
+}
+
+impl Recorder<'_> {
+    pub(crate) fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(EventPaymentContract, ());
+        let client = EventPaymentContractClient::new(&env, &contract_id);
+        let token_address = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+        let token = StellarAssetClient::new(&env, &token_address);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &500, &token_address);
+
+        let mut recorder = Recorder { env, client, token, admin, events: Vec::new() };
+        recorder.capture();
+        recorder
+    }
+
+    // Only the last invocation's events are kept by the Env, so capture after every call
+    pub(crate) fn capture(&mut self) {
+        let ledger = 1000 + self.events.len() as u32;
+        // Token contract events are left out, like the RPC contract filter does
+        for (contract, topics, data) in self.env.events().all().iter().filter(|(c, _, _)| *c == self.client.address) {
+            let encode = |val| ScVal::try_from_val(&self.env, &val).unwrap().to_xdr_base64(Limits::none()).unwrap();
+            let index = self.events.len();
+            self.events.push(RpcEvent {
+                kind: "contract".to_string(),
+                ledger,
+                ledger_closed_at: String::new(),
+                contract_id: ScAddress::from(&contract).to_string(),
+                id: format!("{:019}-{:010}", ledger as u64 * 4096, index),
+                tx_hash: format!("{index:064x}"),
+                topic: topics.iter().map(encode).collect(),
+                value: encode(data),
+            });
+        }
+    }
+
+    pub(crate) fn address(address: &Address) -> String {
+        ScAddress::from(address).to_string()
+    }
+
+    pub(crate) fn event(&mut self, organizer: &Address, name: &str, fee_rate: Option<u32>) -> u64 {
+        let event_id = self.client.create_event(organizer, &SorobanString::from_str(&self.env, name), &fee_rate);
+        self.capture();
+        event_id
+    }
+
+    pub(crate) fn wallet(&mut self, event_id: u64) -> Address {
+        let wallet = Address::generate(&self.env);
+        self.client.register_wallet_for_event(&event_id, &wallet);
+        self.capture();
+        self.token.mint(&wallet, &100_000);
+        wallet
+    }
+
+    pub(crate) fn pay(&mut self, event_id: u64, from: &Address, to: &Address, amount: i128, memo: Option<&str>) {
+        let memo = memo.map(|m| SorobanString::from_str(&self.env, m));
+        self.client.event_payment(&event_id, from, to, &amount, &memo);
+        self.capture();
+    }
+}
+
+/// Two organizers, three events and a mix of payments
+fn festival() -> (Recorder<'static>, Vec<Address>, Vec<u64>) {
+    let mut r = Recorder::new();
+    let organizers = vec![Address::generate(&r.env), Address::generate(&r.env)];
+
+    let main_stage = r.event(&organizers[0], "Main Stage", None);
+    let food = r.event(&organizers[0], "Food Court", Some(300));
+    let after = r.event(&organizers[1], "After Party", Some(1000));
+
+    let (a, b) = (r.wallet(main_stage), r.wallet(main_stage));
+    r.pay(main_stage, &a, &b, 200, Some("beer"));
+    r.pay(main_stage, &b, &a, 1000, None);
+    let (c, d) = (r.wallet(food), r.wallet(food));
+    r.pay(food, &c, &d, 150, None);
+    r.pay(food, &c, &d, 40, Some("fries"));
+    r.pay(food, &d, &c, 999, None);
+    let e = r.wallet(after);
+    let f = r.wallet(after);
+    r.pay(after, &e, &f, 5000, None);
+
+    (r, organizers, vec![main_stage, food, after])
+}
+
+#[test]
+fn test_decodes_contract_events() {
+    let mut r = Recorder::new();
+    let organizer = Address::generate(&r.env);
+    let event_id = r.event(&organizer, "Decode", None);
+    let (from, to) = (r.wallet(event_id), r.wallet(event_id));
+    r.pay(event_id, &from, &to, 200, Some("Table 4"));
+
+    let decoded: Vec<_> = r.events.iter().filter_map(|e| decode(e).unwrap()).collect();
+    assert_eq!(decoded, vec![
+        ContractEvent::EventCreated {
+            event_id,
+            organizer: Recorder::address(&organizer),
+            name: "Decode".to_string(),
+            fee_rate: 500,
+        },
+        ContractEvent::Payment {
+            payment_id: 1,
+            event_id,
+            from: Recorder::address(&from),
+            to: Recorder::address(&to),
+            fee_payer: Recorder::address(&to),
+            amount: 200,
+            fee_amount: 10,
+            fee_rate: 500,
+            memo: Some("Table 4".to_string()),
+        },
+    ]);
+
+    // Other contract events (initialization, registrations) are skipped
+    assert!(r.events.len() > decoded.len());
+
+    // Malformed payloads are reported with the event id
+    let mut broken = r.events.last().unwrap().clone();
+    broken.value = r.events[0].topic[0].clone();
+    let result = decode(&broken);
+    assert!(matches!(result, Err(IndexerError::Decode { ref id, .. }) if *id == broken.id));
+}
+
+#[test]
+fn test_fixture_sync_matches_contract_state() {
+    let (r, organizers, event_ids) = festival();
+    let mut store = Store::open_in_memory().unwrap();
+
+    let stats = sync(&mut Fixture::new(r.events.clone()), &mut store, 0).unwrap();
+    assert_eq!(stats.fetched as usize, r.events.len());
+    assert_eq!(stats.indexed, 3 + 6);
+    assert_eq!(stats.skipped, stats.fetched - stats.indexed);
+
+    // Indexed aggregates agree with the contract's own accounting
+    let volumes = store.volume_per_event().unwrap();
+    assert_eq!(volumes.len(), 3);
+    for (volume, event_id) in volumes.iter().zip(&event_ids) {
+        let event = r.client.get_event(event_id);
+        assert_eq!(volume.event_id, *event_id);
+        assert_eq!(volume.volume as i128, event.total_volume);
+        assert_eq!(volume.fees as i128, r.client.get_event_fees(event_id));
+    }
+    assert_eq!(volumes[1].payments, 3);
+    assert_eq!((volumes[1].volume, volumes[1].fees), (1189, 4 + 1 + 29));
+
+    let fees = store.fees_per_organizer().unwrap();
+    assert_eq!(fees.len(), 2);
+    assert_eq!(fees[0].organizer, Recorder::address(&organizers[1]));
+    assert_eq!((fees[0].events, fees[0].payments, fees[0].fees), (1, 1, 500));
+    assert_eq!((fees[1].events, fees[1].payments, fees[1].fees), (2, 5, 10 + 50 + 34));
+}
+
+#[test]
+fn test_sync_resumes_from_cursor_without_duplicates() {
+    let (mut r, organizers, event_ids) = festival();
+    let mut store = Store::open_in_memory().unwrap();
+    let first_batch = r.events.clone();
+
+    sync(&mut Fixture::new(first_batch.clone()), &mut store, 0).unwrap();
+    assert_eq!(store.cursor().unwrap(), Some(first_batch.last().unwrap().id.clone()));
+
+    // New payments arrive; only events after the stored cursor are fetched
+    let (from, to) = (r.wallet(event_ids[2]), r.wallet(event_ids[2]));
+    r.pay(event_ids[2], &from, &to, 100, None);
+    let stats = sync(&mut Fixture::new(r.events.clone()), &mut store, 0).unwrap();
+    assert_eq!(stats.fetched as usize, r.events.len() - first_batch.len());
+    assert_eq!(stats.indexed, 1);
+
+    // Replaying already indexed events changes nothing
+    let mut fresh_cursor = Store::open_in_memory().unwrap();
+    sync(&mut Fixture::new(r.events.clone()), &mut fresh_cursor, 0).unwrap();
+    let replayed: Vec<(&RpcEvent, ContractEvent)> = r.events.iter()
+        .filter_map(|e| decode(e).unwrap().map(|d| (e, d)))
+        .collect();
+    assert_eq!(store.ingest(&replayed, None).unwrap(), 0);
+    assert_eq!(store.volume_per_event().unwrap(), fresh_cursor.volume_per_event().unwrap());
+    assert_eq!(store.fees_per_organizer().unwrap()[0].organizer, Recorder::address(&organizers[1]));
+    assert_eq!(store.fees_per_organizer().unwrap()[0].fees, 500 + 10);
+}
+
+/// Minimal Soroban RPC: serves `getEvents` from `events` in pages of `limit`
+struct MockRpc {
+    server: Arc<tiny_http::Server>,
+    requests: Arc<Mutex<Vec<serde_json::Value>>>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl MockRpc {
+    fn start(events: Vec<RpcEvent>, fail: bool) -> Self {
+        let server = Arc::new(tiny_http::Server::http("127.0.0.1:0").unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let (srv, log) = (server.clone(), requests.clone());
+        let handle = thread::spawn(move || {
+            for mut request in srv.incoming_requests() {
+                let body: serde_json::Value = serde_json::from_reader(request.as_reader()).unwrap();
+                log.lock().unwrap().push(body.clone());
+
+                let response = if fail {
+                    serde_json::json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32600, "message": "startLedger must be positive" } })
+                } else {
+                    let params = &body["params"];
+                    let limit = params["pagination"]["limit"].as_u64().unwrap() as usize;
+                    let start = match params["pagination"]["cursor"].as_str() {
+                        Some(cursor) => events.iter().position(|e| e.id == cursor).unwrap() + 1,
+                        None => {
+                            let ledger = params["startLedger"].as_u64().unwrap() as u32;
+                            events.iter().position(|e| e.ledger >= ledger).unwrap_or(events.len())
+                        }
+                    };
+                    let page: Vec<_> = events.iter().skip(start).take(limit).cloned().collect();
+                    let page = EventPage {
+                        cursor: page.last().map(|e| e.id.clone()).or_else(|| events.last().map(|e| e.id.clone())),
+                        latest_ledger: events.last().map(|e| e.ledger).unwrap_or(0),
+                        events: page,
+                    };
+                    serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": page })
+                };
+                request.respond(tiny_http::Response::from_string(response.to_string())).unwrap();
+            }
+        });
+
+        MockRpc { server, requests, handle: Some(handle) }
+    }
+
+    fn url(&self) -> String {
+        format!("http://{}", self.server.server_addr().to_ip().unwrap())
+    }
+}
+
+impl Drop for MockRpc {
+    fn drop(&mut self) {
+        self.server.unblock();
+        self.handle.take().unwrap().join().unwrap();
+    }
+}
+
+#[test]
+fn test_rpc_sync_pages_through_mock_server() {
+    let (r, _organizers, event_ids) = festival();
+    let contract_id = Recorder::address(&r.client.address);
+    let rpc = MockRpc::start(r.events.clone(), false);
+    let mut store = Store::open_in_memory().unwrap();
+
+    let mut client = RpcClient::new(&rpc.url(), &contract_id).with_limit(4);
+    let stats = sync(&mut client, &mut store, 1000).unwrap();
+    assert_eq!(stats.fetched as usize, r.events.len());
+    assert_eq!(stats.pages as usize, r.events.len().div_ceil(4));
+    assert_eq!(stats.indexed, 9);
+
+    let requests = rpc.requests.lock().unwrap().clone();
+    // One request per page plus the final empty one
+    assert_eq!(requests.len(), stats.pages as usize + 1);
+    assert_eq!(requests[0]["method"], "getEvents");
+    assert_eq!(requests[0]["params"]["startLedger"], 1000);
+    assert_eq!(requests[0]["params"]["filters"][0]["contractIds"][0], contract_id.as_str());
+    assert!(requests[0]["params"]["pagination"].get("cursor").is_none());
+    assert_eq!(requests[1]["params"]["pagination"]["cursor"], r.events[3].id.as_str());
+    assert!(requests[1]["params"].get("startLedger").is_none());
+
+    let volumes = store.volume_per_event().unwrap();
+    assert_eq!(volumes.iter().map(|v| v.event_id).collect::<Vec<_>>(), event_ids);
+    assert_eq!(volumes.iter().map(|v| v.volume).sum::<i64>(), 200 + 1000 + 150 + 40 + 999 + 5000);
+}
+
+#[test]
+fn test_rpc_errors_are_reported() {
+    let rpc = MockRpc::start(Vec::new(), true);
+    let mut store = Store::open_in_memory().unwrap();
+
+    let result = sync(&mut RpcClient::new(&rpc.url(), "CA"), &mut store, 0);
+    assert!(matches!(result, Err(IndexerError::Rpc(ref message)) if message.contains("startLedger")));
+    assert_eq!(store.cursor().unwrap(), None);
+
+    // Nothing is listening here anymore
+    let url = rpc.url();
+    drop(rpc);
+    let result = sync(&mut RpcClient::new(&url, "CA"), &mut store, 0);
+    assert!(matches!(result, Err(IndexerError::Rpc(_))));
+}
+
+#[test]
+fn test_recorded_fixture_and_http_routes() {
+    let mut store = Store::open_in_memory().unwrap();
+    let stats = sync(&mut Fixture::from_json(RECORDED).unwrap(), &mut store, 0).unwrap();
+    assert_eq!(stats.indexed, 9);
+
+    let (status, body) = server::route(&store, "GET", "/events/volume");
+    assert_eq!(status, 200);
+    let volumes: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(volumes[0]["name"], "Main Stage");
+    assert_eq!(volumes[0]["volume"], 1200);
+    assert_eq!(volumes[2]["fees"], 500);
+
+    let (status, body) = server::route(&store, "GET", "/organizers/fees?format=json");
+    assert_eq!(status, 200);
+    let fees: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(fees.as_array().unwrap().len(), 2);
+    assert_eq!(fees[1]["fees"], 94);
+
+    assert_eq!(server::route(&store, "GET", "/payments").0, 404);
+    assert_eq!(server::route(&store, "POST", "/events/volume").0, 405);
+}
+
+#[test]
+fn test_fixture_accepts_pages_and_event_arrays() {
+    let page: serde_json::Value = serde_json::from_str(RECORDED).unwrap();
+    let events = page["events"].clone();
+    let count = events.as_array().unwrap().len();
+
+    // A getEvents result, bare or still wrapped in the JSON-RPC response, or just its events
+    for json in [
+        page.clone(),
+        serde_json::json!({ "result": page }),
+        events.clone(),
+        serde_json::json!({ "result": events }),
+    ] {
+        let mut fixture = Fixture::from_json(&json.to_string()).unwrap();
+        assert_eq!(fixture.fetch(&StartAt::Ledger(0)).unwrap().events.len(), count, "{json}");
+    }
+
+    assert!(matches!(Fixture::from_json(r#"{"result": 7}"#), Err(IndexerError::Format(_))));
+}
+
+// Regenerate the recorded fixture with `UPDATE_FIXTURES=1 cargo test -p event-indexer`
+#[test]
+fn test_recorded_fixture_is_up_to_date() {
+    let (r, _organizers, _event_ids) = festival();
+    let page = EventPage { latest_ledger: r.events.last().unwrap().ledger, cursor: None, events: r.events };
+    let json = serde_json::to_string_pretty(&page).unwrap() + "\n";
+
+    if std::env::var("UPDATE_FIXTURES").is_ok() {
+        std::fs::write(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/events.json"), &json).unwrap();
+    } else {
+        assert!(json == RECORDED, "fixtures/events.json is stale, rerun with UPDATE_FIXTURES=1");
+    }
+}
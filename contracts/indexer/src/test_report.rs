@@ -0,0 +1,243 @@
+// Settlement reports over a night with every kind of money movement, checked
+// against the contract's own accounting
+
+use payment_with_fee::{FeeMode, LoyaltyConfig, LoyaltyReward, ResaleConfig};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token::TokenClient, Address};
+
+use crate::{
+    settlement_report, sync, test_indexer::Recorder, Fixture, OnChainEvent, SettlementReport, SettlementRow, Store,
+};
+
+struct Night {
+    recorder: Recorder<'static>,
+    organizers: Vec<Address>,
+    event_ids: Vec<u64>,
+}
+
+/// Market: payments in two fee modes, a voucher, primary and resale tickets, an escrow
+/// refund, a cancelled bill, a bill share reclaimed after its deadline and a partial
+/// withdrawal while active.
+/// Stage: one payment, withdrawn in full after closing. Empty: no activity.
+fn night() -> Night {
+    let mut r = Recorder::new();
+    let organizers = vec![Address::generate(&r.env), Address::generate(&r.env)];
+
+    let market = r.event(&organizers[0], "Night Market, Block 7", None);
+    let stage = r.event(&organizers[0], "Stage", Some(1000));
+    let empty = r.event(&organizers[1], "Empty", None);
+    let (a, b, c) = (r.wallet(market), r.wallet(market), r.wallet(market));
+
+    r.client.set_loyalty_config(&market, &LoyaltyConfig { earn_rate_bps: 1000, point_value_bps: 10000 });
+    r.capture();
+    r.pay(market, &a, &b, 1000, None);
+    r.client.set_event_fee_mode(&market, &FeeMode::Sender);
+    r.capture();
+    r.pay(market, &b, &a, 400, Some("stall rent"));
+    r.client.redeem_points(&market, &a, &30, &LoyaltyReward::Voucher);
+    r.capture();
+
+    r.client.set_ticket_price(&market, &300);
+    r.capture();
+    let ticket = r.client.purchase_ticket(&market, &c, &None);
+    r.capture();
+    r.client.set_resale_config(&market, &ResaleConfig { transferable: true, max_markup_bps: 2000, royalty_bps: 1000 });
+    r.capture();
+    r.client.list_ticket(&ticket, &330);
+    r.capture();
+    r.client.buy_ticket(&ticket, &a);
+    r.capture();
+
+    let escrow = r.client.escrow_payment(&market, &a, &b, &250, &3600, &None);
+    r.capture();
+    r.client.dispute_escrow(&escrow);
+    r.capture();
+    r.client.resolve_escrow_dispute(&escrow, &true);
+    r.capture();
+
+    let bill = r.client.open_bill(&market, &b, &500, &None, &3600);
+    r.capture();
+    r.client.contribute_to_bill(&bill, &c, &200);
+    r.capture();
+    r.client.cancel_bill(&bill);
+    r.capture();
+    let bill = r.client.open_bill(&market, &b, &500, &None, &3600);
+    r.capture();
+    r.client.contribute_to_bill(&bill, &c, &150);
+    r.capture();
+    r.env.ledger().with_mut(|l| l.timestamp = 3600);
+    r.client.reclaim_bill_share(&bill, &c);
+    r.capture();
+
+    let (x, y) = (r.wallet(stage), r.wallet(stage));
+    r.pay(stage, &x, &y, 2000, None);
+
+    let admin = r.admin.clone();
+    r.client.set_active_withdrawal_limit(&admin, &5000);
+    r.capture();
+    r.client.withdraw_all_fees(&organizers[0]);
+    r.capture();
+    r.client.set_event_status(&stage, &false);
+    r.capture();
+    r.client.withdraw_event_fees(&stage);
+    r.capture();
+
+    Night { recorder: r, organizers, event_ids: vec![market, stage, empty] }
+}
+
+fn indexed_report(night: &Night) -> SettlementReport {
+    let mut store = Store::open_in_memory().unwrap();
+    sync(&mut Fixture::new(night.recorder.events.clone()), &mut store, 0).unwrap();
+    settlement_report(&store, None).unwrap()
+}
+
+fn on_chain(night: &Night) -> Vec<OnChainEvent> {
+    night.event_ids.iter()
+        .map(|event_id| OnChainEvent {
+            event_id: *event_id,
+            total_volume: night.recorder.client.get_event(event_id).total_volume,
+            event_fees: night.recorder.client.get_event_fees(event_id),
+        })
+        .collect()
+}
+
+#[test]
+fn test_settlement_rows() {
+    let night = night();
+    let report = indexed_report(&night);
+    assert_eq!(report.events.len(), 3);
+
+    // 1000 (fee 50), 400 paid by the sender (fee 20), primary ticket 300, resale 330 (royalty 33)
+    assert_eq!(report.events[0], SettlementRow {
+        event_id: night.event_ids[0],
+        name: "Night Market, Block 7".to_string(),
+        organizer: Recorder::address(&night.organizers[0]),
+        payments: 4,
+        gross_volume: 1000 + 400 + 300 + 330,
+        fees: 50 + 20 + 33,
+        net_to_vendors: 950 + 400 + 300 + 297,
+        // Half of the 73 accumulated when the active event was withdrawn
+        withdrawals: 36,
+        vouchers: 30,
+        refunds: 250 + 200 + 150,
+        outstanding_fees: 103 - 36 - 30,
+    });
+
+    let stage = &report.events[1];
+    assert_eq!((stage.gross_volume, stage.fees, stage.net_to_vendors), (2000, 200, 1800));
+    assert_eq!((stage.withdrawals, stage.outstanding_fees), (100 + 100, 0));
+
+    let empty = &report.events[2];
+    assert_eq!((empty.payments, empty.gross_volume, empty.outstanding_fees), (0, 0, 0));
+    assert_eq!(empty.organizer, Recorder::address(&night.organizers[1]));
+}
+
+#[test]
+fn test_totals_are_sums_of_rows() {
+    let night = night();
+    let report = indexed_report(&night);
+    let rows = &report.events;
+    let t = &report.totals;
+
+    assert_eq!(t.events, 3);
+    assert_eq!(t.payments, rows.iter().map(|r| r.payments).sum::<u64>());
+    assert_eq!(t.gross_volume, rows.iter().map(|r| r.gross_volume).sum::<i64>());
+    assert_eq!(t.fees, rows.iter().map(|r| r.fees).sum::<i64>());
+    assert_eq!(t.net_to_vendors, rows.iter().map(|r| r.net_to_vendors).sum::<i64>());
+    assert_eq!(t.withdrawals, rows.iter().map(|r| r.withdrawals).sum::<i64>());
+    assert_eq!(t.vouchers, rows.iter().map(|r| r.vouchers).sum::<i64>());
+    assert_eq!(t.refunds, rows.iter().map(|r| r.refunds).sum::<i64>());
+    assert_eq!(t.outstanding_fees, rows.iter().map(|r| r.outstanding_fees).sum::<i64>());
+    assert_eq!(t.fees - t.withdrawals - t.vouchers, t.outstanding_fees);
+}
+
+#[test]
+fn test_report_reconciles_with_contract_state() {
+    let night = night();
+    let report = indexed_report(&night);
+    let chain = on_chain(&night);
+    assert_eq!(report.reconcile(&chain), vec![]);
+
+    // Money still held by the contract for the events is exactly the outstanding fees
+    let held: i128 = chain.iter().map(|e| e.event_fees).sum();
+    assert_eq!(held, report.totals.outstanding_fees as i128);
+
+    // A missed payment or withdrawal shows up as a discrepancy
+    let mut drifted = chain.clone();
+    drifted[0].total_volume += 5;
+    drifted[1].event_fees = 7;
+    drifted.push(OnChainEvent { event_id: 99, total_volume: 0, event_fees: 0 });
+    let discrepancies = report.reconcile(&drifted);
+    let fields: Vec<_> = discrepancies.iter().map(|d| (d.event_id, d.field)).collect();
+    assert_eq!(fields, vec![
+        (night.event_ids[0], "gross_volume"),
+        (night.event_ids[1], "outstanding_fees"),
+        (99, "event"),
+    ]);
+    assert_eq!((discrepancies[0].indexed, discrepancies[0].on_chain), (2030, 2035));
+
+    // Events the chain doesn't know about are reported as well
+    let missing = report.reconcile(&chain[..2]);
+    assert_eq!(missing.len(), 1);
+    assert_eq!((missing[0].event_id, missing[0].field, missing[0].indexed), (night.event_ids[2], "event", 1));
+}
+
+#[test]
+fn test_net_to_vendors_includes_fee_credits() {
+    let mut r = Recorder::new();
+    let organizer = Address::generate(&r.env);
+    let event_id = r.event(&organizer, "Credits", None);
+    let (a, b) = (r.wallet(event_id), r.wallet(event_id));
+
+    r.client.set_loyalty_config(&event_id, &LoyaltyConfig { earn_rate_bps: 1000, point_value_bps: 10000 });
+    r.capture();
+    r.pay(event_id, &a, &b, 1000, None);
+    r.client.set_event_fee_mode(&event_id, &FeeMode::Sender);
+    r.capture();
+    r.client.redeem_points(&event_id, &a, &30, &LoyaltyReward::FeeDiscount);
+    r.capture();
+    r.pay(event_id, &a, &b, 1000, None);
+
+    // The sender pays the fee, so the 30 of credit comes back to them and the record shows the 20 kept
+    let mut store = Store::open_in_memory().unwrap();
+    sync(&mut Fixture::new(r.events.clone()), &mut store, 0).unwrap();
+    let row = &settlement_report(&store, None).unwrap().events[0];
+    let token = TokenClient::new(&r.env, &r.token.address);
+    assert_eq!(token.balance(&a), 100_000 - 1000 - 1000 - 20);
+    let received = token.balance(&b) - 100_000;
+    assert_eq!(received, 950 + 1000);
+    assert_eq!(row.net_to_vendors as i128, received);
+    assert_eq!(row.fees, 50 + 20);
+}
+
+#[test]
+fn test_exports_are_deterministic() {
+    let night = night();
+    let report = indexed_report(&night);
+
+    // Syncing the same events in two batches yields the same bytes
+    let mut store = Store::open_in_memory().unwrap();
+    let (first, _) = night.recorder.events.split_at(night.recorder.events.len() / 2);
+    sync(&mut Fixture::new(first.to_vec()), &mut store, 0).unwrap();
+    sync(&mut Fixture::new(night.recorder.events.clone()), &mut store, 0).unwrap();
+    let resynced = settlement_report(&store, None).unwrap();
+    assert_eq!(resynced.to_csv(), report.to_csv());
+    assert_eq!(resynced.to_json(), report.to_json());
+
+    let csv = report.to_csv();
+    let lines: Vec<_> = csv.lines().collect();
+    assert_eq!(lines.len(), 1 + 3 + 1);
+    assert_eq!(lines[0], "event_id,name,organizer,payments,gross_volume,fees,net_to_vendors,withdrawals,vouchers,refunds,outstanding_fees");
+    assert!(lines[1].starts_with(&format!("{},\"Night Market, Block 7\",", night.event_ids[0])));
+    assert!(lines[1].ends_with(",4,2030,103,1947,36,30,600,37"));
+    assert_eq!(lines[4], "TOTAL,,,5,4030,303,3747,236,30,600,37");
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert_eq!(json["events"][0]["name"], "Night Market, Block 7");
+    assert_eq!(json["events"][1]["withdrawals"], 200);
+    assert_eq!(json["totals"]["gross_volume"], 4030);
+
+    // A single event can be reported on its own
+    let single = settlement_report(&store, Some(night.event_ids[1])).unwrap();
+    assert_eq!(single.events, vec![report.events[1].clone()]);
+    assert_eq!(single.totals.outstanding_fees, 0);
+}
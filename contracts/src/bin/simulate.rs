@@ -0,0 +1,56 @@
+//! Scenario simulator CLI
+//!
+//! Usage: cargo run --features testutils --bin simulate -- <scenario.json> [--wasm <contract.wasm>] [--json]
+
+use std::{env, fs, process};
+
+use payment_with_fee::simulation::{self, Scenario};
+
+fn main() {
+    let mut scenario_path = None;
+    let mut wasm_path = None;
+    let mut json = false;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--wasm" => wasm_path = args.next(),
+            "--json" => json = true,
+            _ => scenario_path = Some(arg),
+        }
+    }
+
+    let Some(scenario_path) = scenario_path else {
+        eprintln!("usage: simulate <scenario.json> [--wasm <contract.wasm>] [--json]");
+        process::exit(2);
+    };
+
+    let input = fs::read_to_string(&scenario_path).unwrap_or_else(|e| {
+        eprintln!("❌ Could not read {scenario_path}: {e}");
+        process::exit(1);
+    });
+    let scenario = Scenario::from_json(&input).unwrap_or_else(|e| {
+        eprintln!("❌ {e}");
+        process::exit(1);
+    });
+
+    let report = match wasm_path {
+        Some(path) => {
+            let wasm = fs::read(&path).unwrap_or_else(|e| {
+                eprintln!("❌ Could not read {path}: {e}");
+                process::exit(1);
+            });
+            simulation::run_with_wasm(&scenario, &wasm)
+        }
+        None => simulation::run(&scenario),
+    };
+
+    match report {
+        Ok(report) if json => println!("{}", serde_json::to_string_pretty(&report).unwrap()),
+        Ok(report) => println!("{report}"),
+        Err(e) => {
+            eprintln!("❌ {e}");
+            process::exit(1);
+        }
+    }
+}
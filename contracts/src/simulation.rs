@@ -0,0 +1,476 @@
+//! Offline scenario simulator for fee and settlement forecasting.
+//!
+//! Runs a festival scenario against `EventPaymentContract` inside the soroban
+//! test `Env`: events are created, attendees and vendors are registered and
+//! funded with a Stellar Asset Contract token, payments are drawn from the
+//! scenario's distribution and, at the end, events are closed and fees are
+//! withdrawn. The report aggregates organizer revenue, event fees, the
+//! metered resources of every contract call and the final balances.
+//!
+//! Resource numbers are only as realistic as the contract that is registered:
+//! pass the compiled WASM (see `run_with_wasm`) to include VM costs.
+extern crate std;
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::{Deserialize, Serialize};
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env,
+};
+
+use crate::{ContractError, EventPaymentContract, EventPaymentContractClient};
+
+/// Scenario description, usually loaded from a JSON file.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    /// Default fee rate in basis points used to initialize the contract
+    #[serde(default = "default_fee_rate")]
+    pub default_fee_rate: u32,
+    /// Seed for the payment generator, so runs are reproducible
+    #[serde(default)]
+    pub seed: u64,
+    pub events: Vec<EventScenario>,
+}
+
+/// One event/festival inside a scenario
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventScenario {
+    pub name: String,
+    /// Custom fee rate in basis points (falls back to the default rate)
+    #[serde(default)]
+    pub fee_rate: Option<u32>,
+    pub attendees: u32,
+    pub vendors: u32,
+    /// Tokens minted to every attendee before payments start
+    pub initial_balance: i64,
+    /// Number of payments attempted from attendees to vendors
+    pub payments: u32,
+    pub distribution: PaymentDistribution,
+    /// Close the event and withdraw fees once payments are done
+    #[serde(default = "default_settle")]
+    pub settle: bool,
+}
+
+/// How payment amounts are drawn (serde can't buffer i128 inside tagged
+/// enums, so scenario amounts are i64)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PaymentDistribution {
+    Fixed { amount: i64 },
+    Uniform { min: i64, max: i64 },
+    Weighted { tiers: Vec<WeightedAmount> },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct WeightedAmount {
+    pub amount: i64,
+    pub weight: u32,
+}
+
+fn default_fee_rate() -> u32 {
+    500
+}
+
+fn default_settle() -> bool {
+    true
+}
+
+/// Result of a simulation run
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SimulationReport {
+    pub scenario: String,
+    pub events: Vec<EventReport>,
+    /// Resource usage aggregated per contract function
+    pub calls: Vec<CallStats>,
+    pub balances: FinalBalances,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EventReport {
+    pub event_id: u64,
+    pub name: String,
+    pub fee_rate: u32,
+    pub payments_succeeded: u32,
+    pub payments_failed: u32,
+    /// Sum of all successful payment amounts (matches `Event.total_volume`)
+    pub gross_volume: i128,
+    /// Event fees held by the contract for the organizer to withdraw
+    /// (not protocol revenue; the protocol admin only earns on general payments)
+    pub event_fees: i128,
+    /// Amount received by vendors after fees
+    pub vendor_net: i128,
+    /// Fees withdrawn by the organizer (zero when the event is not settled)
+    pub organizer_revenue: i128,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CallStats {
+    pub function: String,
+    pub calls: u32,
+    pub total_instructions: i64,
+    pub max_instructions: i64,
+    pub max_mem_bytes: i64,
+    pub max_read_entries: u32,
+    pub max_write_entries: u32,
+    pub max_write_bytes: u32,
+    /// Estimated resource fee in stroops, summed over all calls
+    pub total_fee: i64,
+    pub max_fee: i64,
+}
+
+impl CallStats {
+    pub fn avg_instructions(&self) -> i64 {
+        if self.calls == 0 {
+            0
+        } else {
+            self.total_instructions / self.calls as i64
+        }
+    }
+
+    pub fn avg_fee(&self) -> i64 {
+        if self.calls == 0 {
+            0
+        } else {
+            self.total_fee / self.calls as i64
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FinalBalances {
+    pub attendees: i128,
+    pub vendors: i128,
+    pub organizers: i128,
+    pub contract: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SimulationError {
+    /// The scenario itself is inconsistent
+    InvalidScenario(String),
+    /// A setup call (create, register, withdraw...) was rejected by the contract
+    Contract { call: &'static str, error: ContractError },
+    /// A setup call failed at the host level
+    Invoke { call: &'static str },
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationError::InvalidScenario(reason) => write!(f, "invalid scenario: {reason}"),
+            SimulationError::Contract { call, error } => write!(f, "{call} failed: {error:?}"),
+            SimulationError::Invoke { call } => write!(f, "{call} failed at the host level"),
+        }
+    }
+}
+
+impl Scenario {
+    pub fn from_json(json: &str) -> Result<Self, SimulationError> {
+        let scenario: Scenario = serde_json::from_str(json)
+            .map_err(|e| SimulationError::InvalidScenario(e.to_string()))?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    pub fn validate(&self) -> Result<(), SimulationError> {
+        let invalid = |reason: &str| Err(SimulationError::InvalidScenario(reason.to_string()));
+
+        if self.events.is_empty() {
+            return invalid("scenario has no events");
+        }
+        for event in &self.events {
+            if event.attendees == 0 || event.vendors == 0 {
+                return invalid("events need at least one attendee and one vendor");
+            }
+            if event.initial_balance < 0 {
+                return invalid("initial_balance cannot be negative");
+            }
+            match &event.distribution {
+                PaymentDistribution::Fixed { amount } if *amount <= 0 => {
+                    return invalid("fixed amount must be positive");
+                }
+                PaymentDistribution::Uniform { min, max } if *min <= 0 || max < min => {
+                    return invalid("uniform range must be positive and min <= max");
+                }
+                PaymentDistribution::Weighted { tiers }
+                    if tiers.is_empty()
+                        || tiers.iter().all(|t| t.weight == 0)
+                        || tiers.iter().any(|t| t.amount <= 0) =>
+                {
+                    return invalid("weighted tiers need positive amounts and a non-zero weight");
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Small deterministic generator (splitmix64), enough to spread payments
+pub(crate) struct Rng(pub(crate) u64);
+
+impl Rng {
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn amount(&mut self, distribution: &PaymentDistribution) -> i128 {
+        match distribution {
+            PaymentDistribution::Fixed { amount } => *amount as i128,
+            PaymentDistribution::Uniform { min, max } => {
+                let span = (max - min) as u64 + 1;
+                (*min + (self.below(span)) as i64) as i128
+            }
+            PaymentDistribution::Weighted { tiers } => {
+                let total: u64 = tiers.iter().map(|t| t.weight as u64).sum();
+                let mut pick = self.below(total);
+                for tier in tiers {
+                    if pick < tier.weight as u64 {
+                        return tier.amount as i128;
+                    }
+                    pick -= tier.weight as u64;
+                }
+                tiers[tiers.len() - 1].amount as i128
+            }
+        }
+    }
+}
+
+/// Run a scenario against the natively registered contract
+pub fn run(scenario: &Scenario) -> Result<SimulationReport, SimulationError> {
+    Simulation::new(scenario, None).run()
+}
+
+/// Run a scenario against a compiled contract WASM, metering VM costs too
+pub fn run_with_wasm(scenario: &Scenario, wasm: &[u8]) -> Result<SimulationReport, SimulationError> {
+    Simulation::new(scenario, Some(wasm)).run()
+}
+
+struct Simulation<'a> {
+    scenario: &'a Scenario,
+    env: Env,
+    client: EventPaymentContractClient<'a>,
+    token: TokenClient<'a>,
+    token_admin: StellarAssetClient<'a>,
+    calls: BTreeMap<&'static str, CallStats>,
+    attendees: Vec<Address>,
+    vendors: Vec<Address>,
+    organizers: Vec<Address>,
+}
+
+impl<'a> Simulation<'a> {
+    fn new(scenario: &'a Scenario, wasm: Option<&[u8]>) -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = match wasm {
+            Some(wasm) => env.register(wasm, ()),
+            None => env.register(EventPaymentContract, ()),
+        };
+        let client = EventPaymentContractClient::new(&env, &contract_id);
+
+        let issuer = Address::generate(&env);
+        let token_address = env.register_stellar_asset_contract_v2(issuer).address();
+        let token = TokenClient::new(&env, &token_address);
+        let token_admin = StellarAssetClient::new(&env, &token_address);
+
+        Simulation {
+            scenario,
+            env,
+            client,
+            token,
+            token_admin,
+            calls: BTreeMap::new(),
+            attendees: Vec::new(),
+            vendors: Vec::new(),
+            organizers: Vec::new(),
+        }
+    }
+
+    fn run(mut self) -> Result<SimulationReport, SimulationError> {
+        self.scenario.validate()?;
+
+        let admin = Address::generate(&self.env);
+        let result = self.client.try_initialize(&admin, &self.scenario.default_fee_rate, &self.token.address);
+        self.check("initialize", result)?;
+
+        let mut rng = Rng(self.scenario.seed);
+        let mut events = Vec::new();
+        for event in &self.scenario.events {
+            events.push(self.run_event(event, &mut rng)?);
+        }
+
+        let balances = FinalBalances {
+            attendees: self.total_balance(&self.attendees),
+            vendors: self.total_balance(&self.vendors),
+            organizers: self.total_balance(&self.organizers),
+            contract: self.token.balance(&self.client.address),
+        };
+
+        Ok(SimulationReport {
+            scenario: self.scenario.name.clone(),
+            events,
+            calls: self.calls.into_values().collect(),
+            balances,
+        })
+    }
+
+    fn run_event(&mut self, scenario: &EventScenario, rng: &mut Rng) -> Result<EventReport, SimulationError> {
+        let organizer = Address::generate(&self.env);
+        self.organizers.push(organizer.clone());
+
+        let name = soroban_sdk::String::from_str(&self.env, &scenario.name);
+        let result = self.client.try_create_event(&organizer, &name, &scenario.fee_rate);
+        let event_id = self.check("create_event", result)?;
+
+        let attendees = self.register_wallets(event_id, scenario.attendees)?;
+        let vendors = self.register_wallets(event_id, scenario.vendors)?;
+        for attendee in &attendees {
+            self.token_admin.mint(attendee, &(scenario.initial_balance as i128));
+        }
+        let vendor_start = self.total_balance(&vendors);
+
+        let mut report = EventReport {
+            event_id,
+            name: scenario.name.clone(),
+            ..Default::default()
+        };
+
+        for _ in 0..scenario.payments {
+            let from = &attendees[rng.below(attendees.len() as u64) as usize];
+            let to = &vendors[rng.below(vendors.len() as u64) as usize];
+            let amount = rng.amount(&scenario.distribution);
+
+            let result = self.client.try_event_payment(&event_id, from, to, &amount, &None);
+            self.record("event_payment");
+            match result {
+                Ok(Ok(_)) => {
+                    report.payments_succeeded += 1;
+                    report.gross_volume += amount;
+                }
+                _ => report.payments_failed += 1,
+            }
+        }
+
+        let event = self.client.get_event(&event_id);
+        report.fee_rate = event.fee_rate;
+        report.event_fees = self.client.get_event_fees(&event_id);
+        report.vendor_net = self.total_balance(&vendors) - vendor_start;
+
+        if scenario.settle {
+            let result = self.client.try_set_event_status(&event_id, &false);
+            self.check("set_event_status", result)?;
+            let result = self.client.try_withdraw_event_fees(&event_id);
+            report.organizer_revenue = self.check("withdraw_event_fees", result)?;
+        }
+
+        self.attendees.extend(attendees);
+        self.vendors.extend(vendors);
+        Ok(report)
+    }
+
+    fn register_wallets(&mut self, event_id: u64, count: u32) -> Result<Vec<Address>, SimulationError> {
+        let mut wallets = Vec::new();
+        for _ in 0..count {
+            let wallet = Address::generate(&self.env);
+            let result = self.client.try_register_wallet_for_event(&event_id, &wallet);
+            self.check("register_wallet_for_event", result)?;
+            wallets.push(wallet);
+        }
+        Ok(wallets)
+    }
+
+    fn total_balance(&self, wallets: &[Address]) -> i128 {
+        wallets.iter().map(|w| self.token.balance(w)).sum()
+    }
+
+    /// Record the resources of the last invocation and unwrap its result
+    fn check<T, C, I>(
+        &mut self,
+        call: &'static str,
+        result: Result<Result<T, C>, Result<ContractError, I>>,
+    ) -> Result<T, SimulationError> {
+        self.record(call);
+        match result {
+            Ok(Ok(value)) => Ok(value),
+            Err(Ok(error)) => Err(SimulationError::Contract { call, error }),
+            _ => Err(SimulationError::Invoke { call }),
+        }
+    }
+
+    fn record(&mut self, call: &'static str) {
+        let estimate = self.env.cost_estimate();
+        let resources = estimate.resources();
+        let fee = estimate.fee().total;
+
+        let stats = self.calls.entry(call).or_insert_with(|| CallStats {
+            function: call.to_string(),
+            ..Default::default()
+        });
+        stats.calls += 1;
+        stats.total_instructions += resources.instructions;
+        stats.max_instructions = stats.max_instructions.max(resources.instructions);
+        stats.max_mem_bytes = stats.max_mem_bytes.max(resources.mem_bytes);
+        stats.max_read_entries = stats
+            .max_read_entries
+            .max(resources.disk_read_entries + resources.memory_read_entries);
+        stats.max_write_entries = stats.max_write_entries.max(resources.write_entries);
+        stats.max_write_bytes = stats.max_write_bytes.max(resources.write_bytes);
+        stats.total_fee += fee;
+        stats.max_fee = stats.max_fee.max(fee);
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Scenario: {}", self.scenario)?;
+        writeln!(f)?;
+        writeln!(f, "Events")?;
+        for e in &self.events {
+            writeln!(f, "  #{} {} (fee rate {} bps)", e.event_id, e.name, e.fee_rate)?;
+            writeln!(f, "    payments:          {} ok / {} failed", e.payments_succeeded, e.payments_failed)?;
+            writeln!(f, "    gross volume:      {}", e.gross_volume)?;
+            writeln!(f, "    event fees:        {}", e.event_fees)?;
+            writeln!(f, "    vendor net:        {}", e.vendor_net)?;
+            writeln!(f, "    organizer revenue: {}", e.organizer_revenue)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Resources per call (avg / max)")?;
+        for c in &self.calls {
+            writeln!(
+                f,
+                "  {:<28} calls {:>6}  cpu {:>10} / {:>10}  mem max {:>9}  writes max {:>3}  fee {:>8} / {:>8}",
+                c.function,
+                c.calls,
+                c.avg_instructions(),
+                c.max_instructions,
+                c.max_mem_bytes,
+                c.max_write_entries,
+                c.avg_fee(),
+                c.max_fee,
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Final balances")?;
+        writeln!(f, "  attendees:  {}", self.balances.attendees)?;
+        writeln!(f, "  vendors:    {}", self.balances.vendors)?;
+        writeln!(f, "  organizers: {}", self.balances.organizers)?;
+        write!(f, "  contract:   {}", self.balances.contract)
+    }
+}
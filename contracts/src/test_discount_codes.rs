@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+// Hashed discount codes for ticket sales and fee waivers
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, assert_last_event, MockTokenClient};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, BytesN, String};
+
+#[test]
+fn test_discount_code_on_ticket_purchase() {
+    let (env, client, _admin, token_address) = setup_test();
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token = MockTokenClient::new(&env, &token_address);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Promo Night"), &None);
+
+    let code = Bytes::from_slice(&env, b"EARLYBIRD");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+
+    assert_eq!(client.try_purchase_ticket(&event_id, &buyer, &None), Err(Ok(ContractError::InvalidState)));
+    client.set_ticket_price(&event_id, &200);
+
+    // 25% off, two uses, valid for one hour
+    client.create_discount_code(&event_id, &code_hash, &DiscountKind::TicketPercent(2500), &2, &3600);
+    assert_eq!(
+        client.try_create_discount_code(&event_id, &code_hash, &DiscountKind::FeeWaiver, &1, &3600),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+
+    let ticket_id = client.purchase_ticket(&event_id, &buyer, &Some(code.clone()));
+    let ticket = client.get_ticket(&ticket_id);
+    assert_eq!(ticket.owner, buyer);
+    assert_eq!(ticket.face_value, 200);
+    assert_eq!(token.balance(&buyer), 1000000 - 150);
+    assert_eq!(token.balance(&organizer), 150);
+    assert_eq!(client.get_discount_code(&event_id, &code_hash).uses, 1);
+
+    // Wrong code, then the code is used up
+    let wrong = Bytes::from_slice(&env, b"LATEBIRD");
+    assert_eq!(client.try_purchase_ticket(&event_id, &buyer, &Some(wrong)), Err(Ok(ContractError::RecordNotFound)));
+    client.purchase_ticket(&event_id, &buyer, &Some(code.clone()));
+    assert_eq!(
+        client.try_purchase_ticket(&event_id, &buyer, &Some(code.clone())),
+        Err(Ok(ContractError::DiscountCodeExhausted))
+    );
+
+    // Full price without a code
+    client.purchase_ticket(&event_id, &buyer, &None);
+    assert_eq!(token.balance(&organizer), 150 + 150 + 200);
+    assert_eq!(client.balance(&buyer), 3);
+    assert_eq!(client.get_event(&event_id).total_volume, 150 + 150 + 200);
+}
+
+#[test]
+fn test_discount_code_fee_waiver_and_expiry() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, sender, receiver) = setup_event_with_wallets(&env, &client);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    let code = Bytes::from_slice(&env, b"NOFEE");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    client.create_discount_code(&event_id, &code_hash, &DiscountKind::FeeWaiver, &10, &1000);
+
+    // Recipient receives the full amount and no fee is accumulated
+    let payment_id = client.event_payment_with_code(&event_id, &sender, &receiver, &400, &None, &code);
+    assert_last_event(&env, &client.address, &PaymentEvent {
+        payment_id,
+        event_id,
+        from: sender.clone(),
+        to: receiver.clone(),
+        fee_payer: receiver.clone(),
+        amount: 400,
+        fee_amount: 0,
+        fee_rate: 0,
+        memo: None,
+    });
+    assert_eq!(token.balance(&receiver), 400);
+    assert_eq!(client.get_event_fees(&event_id), 0);
+
+    // The waived payment follows the event's fee mode: in Sender mode nothing is added on top
+    client.set_event_fee_mode(&event_id, &FeeMode::Sender);
+    let before = token.balance(&sender);
+    let payment_id = client.event_payment_with_code(&event_id, &sender, &receiver, &400, &None, &code);
+    assert_eq!(client.get_payment(&payment_id).fee_payer, sender);
+    assert_eq!(token.balance(&sender), before - 400);
+    assert_eq!(token.balance(&receiver), 800);
+    assert_eq!(client.get_event_fees(&event_id), 0);
+
+    // A fee waiver doesn't apply to ticket purchases
+    client.set_ticket_price(&event_id, &100);
+    assert_eq!(
+        client.try_purchase_ticket(&event_id, &sender, &Some(code.clone())),
+        Err(Ok(ContractError::DiscountNotApplicable))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    assert_eq!(
+        client.try_event_payment_with_code(&event_id, &sender, &receiver, &400, &None, &code),
+        Err(Ok(ContractError::Expired))
+    );
+}
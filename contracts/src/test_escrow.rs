@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+// Escrowed payments: confirmation, timeout release and dispute arbitration
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, assert_last_event, MockTokenClient};
+use soroban_sdk::testutils::Ledger;
+
+#[test]
+fn test_escrow_confirm_and_timeout_release() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, buyer, seller) = setup_event_with_wallets(&env, &client);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    // Funds sit in the contract until confirmed
+    let escrow_id = client.escrow_payment(&event_id, &buyer, &seller, &200, &3600, &None);
+    assert_eq!(token.balance(&buyer), 1000000 - 200);
+    assert_eq!(token.balance(&client.address), 200);
+    assert_eq!(client.get_event_fees(&event_id), 0);
+
+    let escrow = client.get_escrow(&escrow_id);
+    assert_eq!(escrow.status, EscrowStatus::Held);
+    assert_eq!(escrow.release_at, 3600);
+
+    // Seller cannot pull funds before the window ends
+    assert_eq!(client.try_release_escrow(&escrow_id), Err(Ok(ContractError::NotYetDue)));
+
+    // Buyer confirmation pays the seller with the regular event fee
+    let payment_id = client.confirm_escrow(&escrow_id);
+    assert_last_event(&env, &client.address, &EscrowReleased { escrow_id, event_id, payment_id });
+    assert_eq!(token.balance(&seller), 190);
+    assert_eq!(client.get_event_fees(&event_id), 10);
+    assert_eq!(client.get_event(&event_id).total_volume, 200);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Released);
+    assert_eq!(client.try_confirm_escrow(&escrow_id), Err(Ok(ContractError::InvalidState)));
+
+    // Without confirmation, anyone can release after the timeout
+    let second = client.escrow_payment(&event_id, &buyer, &seller, &100, &3600, &None);
+    env.ledger().with_mut(|l| l.timestamp = 3600);
+    assert_eq!(client.try_dispute_escrow(&second), Err(Ok(ContractError::Expired)));
+    client.release_escrow(&second);
+    assert_eq!(token.balance(&seller), 190 + 95);
+    assert_eq!(token.balance(&client.address), 15);
+}
+
+#[test]
+fn test_escrow_dispute_arbitration() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, buyer, seller) = setup_event_with_wallets(&env, &client);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    assert_eq!(
+        client.try_escrow_payment(&event_id, &buyer, &seller, &200, &0, &None),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+
+    // Disputed escrow refunded by the organizer, without fee
+    let refunded = client.escrow_payment(&event_id, &buyer, &seller, &200, &3600, &None);
+    client.dispute_escrow(&refunded);
+    assert_last_event(&env, &client.address, &EscrowDisputed { escrow_id: refunded, event_id });
+
+    // Disputed funds can't be released by timeout
+    env.ledger().with_mut(|l| l.timestamp = 7200);
+    assert_eq!(client.try_release_escrow(&refunded), Err(Ok(ContractError::InvalidState)));
+
+    client.resolve_escrow_dispute(&refunded, &true);
+    assert_last_event(&env, &client.address, &EscrowRefunded { escrow_id: refunded, event_id, amount: 200 });
+    assert_eq!(token.balance(&buyer), 1000000);
+    assert_eq!(client.get_event_fees(&event_id), 0);
+    assert_eq!(client.get_escrow(&refunded).status, EscrowStatus::Refunded);
+
+    // Disputed escrow decided in favour of the seller
+    let released = client.escrow_payment(&event_id, &buyer, &seller, &200, &3600, &None);
+    client.dispute_escrow(&released);
+    client.resolve_escrow_dispute(&released, &false);
+    assert_eq!(token.balance(&seller), 190);
+    assert_eq!(client.get_escrow(&released).status, EscrowStatus::Released);
+
+    // Only disputed escrows can be arbitrated
+    assert_eq!(client.try_resolve_escrow_dispute(&released, &true), Err(Ok(ContractError::InvalidState)));
+    assert_eq!(client.try_get_escrow(&99), Err(Ok(ContractError::RecordNotFound)));
+}
@@ -1,7 +1,8 @@
 #![cfg(test)]
+#![allow(clippy::bool_assert_comparison)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String, contractimpl, contract};
+use soroban_sdk::{testutils::{Address as _, Events as _}, Address, Env, IntoVal, String, contractimpl, contract};
 
 // Mock Token Contract for tests
 #[contract]
@@ -66,7 +67,7 @@ impl MockToken {
 }
 
 // Helper function to set up tests
-fn setup_test<'a>() -> (Env, EventPaymentContractClient<'a>, Address, Address) {
+pub(crate) fn setup_test<'a>() -> (Env, EventPaymentContractClient<'a>, Address, Address) {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -274,21 +275,21 @@ fn test_payment_requires_registration() {
     client.register_wallet_for_event(&event_id, &sender);
 
     // Payment should fail if receiver is not registered
-    let result = client.try_event_payment(&event_id, &sender, &receiver, &100);
+    let result = client.try_event_payment(&event_id, &sender, &receiver, &100, &None);
     assert!(result.is_err());
 
     // Register receiver
     client.register_wallet_for_event(&event_id, &receiver);
 
     // Agora pagamento deve funcionar
-    client.event_payment(&event_id, &sender, &receiver, &100);
+    client.event_payment(&event_id, &sender, &receiver, &100, &None);
 
     // Payment with unregistered wallet as sender should fail
-    let result = client.try_event_payment(&event_id, &unregistered, &receiver, &50);
+    let result = client.try_event_payment(&event_id, &unregistered, &receiver, &50, &None);
     assert!(result.is_err());
 
     // Payment with unregistered wallet as receiver should fail
-    let result = client.try_event_payment(&event_id, &sender, &unregistered, &50);
+    let result = client.try_event_payment(&event_id, &sender, &unregistered, &50, &None);
     assert!(result.is_err());
 }
 
@@ -344,7 +345,7 @@ fn test_event_payment() {
     client.register_wallet_for_event(&event_id, &receiver);
 
     // Make payment of 200 (fee = 10, net = 190)
-    client.event_payment(&event_id, &sender, &receiver, &200);
+    client.event_payment(&event_id, &sender, &receiver, &200, &None);
 
     // Verify taxas acumuladas para o organizador
     assert_eq!(client.get_event_fees(&event_id), 10); // Taxa travada no contrato
@@ -355,7 +356,7 @@ fn test_event_payment() {
 
     // Try payment with unregistered wallet should fail
     let unregistered = Address::generate(&env);
-    let result = client.try_event_payment(&event_id, &unregistered, &receiver, &100);
+    let result = client.try_event_payment(&event_id, &unregistered, &receiver, &100, &None);
     assert!(result.is_err());
 }
 
@@ -486,8 +487,8 @@ fn test_event_fee_withdrawal() {
     client.register_wallet_for_event(&event_id, &sender);
     client.register_wallet_for_event(&event_id, &receiver);
 
-    client.event_payment(&event_id, &sender, &receiver, &200); // Taxa: 10 (5% de 200)
-    client.event_payment(&event_id, &sender, &receiver, &300); // Taxa: 15 (5% de 300)
+    client.event_payment(&event_id, &sender, &receiver, &200, &None); // Taxa: 10 (5% de 200)
+    client.event_payment(&event_id, &sender, &receiver, &300, &None); // Taxa: 15 (5% de 300)
 
     // Verify taxas acumuladas
     assert_eq!(client.get_event_fees(&event_id), 25); // 10 + 15
@@ -533,6 +534,37 @@ fn test_create_event_with_allowance() {
     assert_eq!(allowance, max_allowance);
 }
 
+#[test]
+fn test_event_allowance_on_a_live_network() {
+    use soroban_sdk::{testutils::Ledger, token::{StellarAssetClient, TokenClient}};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.sequence_number = 5_000_000);
+    let client = EventPaymentContractClient::new(&env, &env.register(EventPaymentContract, ()));
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    client.initialize(&Address::generate(&env), &500, &token);
+    let organizer = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&organizer, &1000);
+
+    // Far past ledger 3_110_400 the approvals still land in the future, and both are announced
+    let event_id = client.create_event_with_allowance(&organizer, &String::from_str(&env, "Live"), &None, &400);
+    assert_last_event(&env, &client.address, &EventAllowanceIncreased {
+        event_id,
+        organizer: organizer.clone(),
+        added: 400,
+        allowance: 400,
+    });
+    client.increase_event_allowance(&event_id, &100);
+    assert_last_event(&env, &client.address, &EventAllowanceIncreased {
+        event_id,
+        organizer: organizer.clone(),
+        added: 100,
+        allowance: 500,
+    });
+    assert_eq!(TokenClient::new(&env, &token).allowance(&organizer, &client.address), 500);
+}
+
 #[test]
 fn test_increase_event_allowance() {
     let (env, client, _admin, _token_address) = setup_test();
@@ -581,4 +613,159 @@ fn test_admin_only_functions() {
     // Verify fee rate was not changed by non-admin
     let final_config = client.get_config(&admin);
     assert_eq!(final_config.default_fee_rate, 30); // Ainda deve ser 30
-}
\ No newline at end of file
+}
+// Helper to create an event with two registered wallets
+pub(crate) fn setup_event_with_wallets(env: &Env, client: &EventPaymentContractClient) -> (u64, Address, Address, Address) {
+    let organizer = Address::generate(env);
+    let sender = Address::generate(env);
+    let receiver = Address::generate(env);
+
+    let event_id = client.create_event(&organizer, &String::from_str(env, "Limits Festival"), &None);
+    client.register_wallet_for_event(&event_id, &sender);
+    client.register_wallet_for_event(&event_id, &receiver);
+
+    (event_id, organizer, sender, receiver)
+}
+
+// Helper to assert the last event published by the contract
+pub(crate) fn assert_last_event(env: &Env, contract_id: &Address, expected: &impl soroban_sdk::Event) {
+    let (address, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(address, *contract_id);
+    assert_eq!(topics, expected.topics(env));
+    assert_eq!(soroban_sdk::vec![env, data], soroban_sdk::vec![env, expected.data(env)]);
+}
+
+#[test]
+fn test_admin_config_changes_emit_events() {
+    let (env, client, admin, token_address) = setup_test();
+    assert_last_event(&env, &client.address, &ContractInitialized {
+        admin: admin.clone(),
+        token: token_address,
+        default_fee_rate: 500,
+    });
+
+    client.update_default_fee_rate(&admin, &300);
+    assert_last_event(&env, &client.address, &DefaultFeeRateUpdated { admin: admin.clone(), old_rate: 500, new_rate: 300 });
+
+    client.set_fee_policy(&admin, &FeePolicy { rounding: RoundingMode::Ceil, min_fee: 2 });
+    assert_last_event(&env, &client.address, &FeePolicyUpdated { admin: admin.clone(), rounding: RoundingMode::Ceil, min_fee: 2 });
+
+    client.set_active_withdrawal_limit(&admin, &2500);
+    assert_last_event(&env, &client.address, &WithdrawalLimitUpdated { admin, limit_bps: 2500 });
+}
+
+#[test]
+fn test_event_state_changes_emit_events() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let organizer = Address::generate(&env);
+    let wallet = Address::generate(&env);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Indexed"), &None);
+
+    client.register_wallet_for_event(&event_id, &wallet);
+    assert_last_event(&env, &client.address, &WalletRegistered { event_id, wallet: wallet.clone() });
+
+    client.unregister_wallet_from_event(&event_id, &wallet);
+    assert_last_event(&env, &client.address, &WalletUnregistered { event_id, wallet: wallet.clone() });
+    client.register_wallet_for_event(&event_id, &wallet);
+
+    let limit = SpendingLimit { per_payment: Some(100), per_day: None, per_event: None };
+    client.set_event_spending_limit(&event_id, &limit);
+    assert_last_event(&env, &client.address, &SpendingLimitSet {
+        event_id,
+        wallet: None,
+        set_by: organizer.clone(),
+        limit: limit.clone(),
+    });
+    client.set_self_spending_limit(&event_id, &wallet, &limit);
+    assert_last_event(&env, &client.address, &SpendingLimitSet {
+        event_id,
+        wallet: Some(wallet.clone()),
+        set_by: wallet.clone(),
+        limit,
+    });
+
+    let delegate = Address::generate(&env);
+    client.create_session_grant(&wallet, &delegate, &event_id, &400, &3600);
+    assert_last_event(&env, &client.address, &SessionGrantCreated {
+        event_id,
+        grantor: wallet.clone(),
+        delegate: delegate.clone(),
+        amount_cap: 400,
+        expires_at: 3600,
+    });
+    client.revoke_session_grant(&wallet, &delegate, &event_id);
+    assert_last_event(&env, &client.address, &SessionGrantRevoked { event_id, grantor: wallet.clone(), delegate });
+
+    client.set_event_fee_mode(&event_id, &FeeMode::Sender);
+    assert_last_event(&env, &client.address, &FeeModeSet { event_id, mode: FeeMode::Sender });
+    client.set_ticket_price(&event_id, &250);
+    assert_last_event(&env, &client.address, &TicketPriceSet { event_id, price: 250 });
+    client.set_tip_fee_rate(&event_id, &100);
+    assert_last_event(&env, &client.address, &TipFeeRateSet { event_id, fee_rate: 100 });
+    let loyalty = LoyaltyConfig { earn_rate_bps: 100, point_value_bps: 50 };
+    client.set_loyalty_config(&event_id, &loyalty);
+    assert_last_event(&env, &client.address, &LoyaltyConfigSet { event_id, config: loyalty });
+    let base_uri = String::from_str(&env, "https://tickets.example/1/");
+    client.set_ticket_base_uri(&event_id, &base_uri);
+    assert_last_event(&env, &client.address, &TicketBaseUriSet { event_id, base_uri });
+
+    client.authorize_fee_payments(&organizer, &500);
+    assert_last_event(&env, &client.address, &FeeAuthorizationSet { fee_payer: organizer.clone(), max_fee_amount: 500 });
+    client.increase_event_allowance(&event_id, &300);
+    assert_last_event(&env, &client.address, &EventAllowanceIncreased {
+        event_id,
+        organizer: organizer.clone(),
+        added: 300,
+        allowance: 800,
+    });
+    client.revoke_fee_authorization(&organizer);
+    assert_last_event(&env, &client.address, &FeeAuthorizationSet { fee_payer: organizer.clone(), max_fee_amount: 0 });
+
+    client.set_event_status(&event_id, &false);
+    assert_last_event(&env, &client.address, &EventStatusChanged { event_id, is_active: false });
+}
+
+#[test]
+fn test_fee_withdrawal_emits_event() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, organizer, sender, receiver) = setup_event_with_wallets(&env, &client);
+
+    client.event_payment(&event_id, &sender, &receiver, &1000, &None);
+    client.set_event_status(&event_id, &false);
+    client.withdraw_event_fees(&event_id);
+    assert_last_event(&env, &client.address, &FeesWithdrawn { event_id, organizer, amount: 50 });
+
+    // Nothing left to withdraw: no event
+    client.withdraw_event_fees(&event_id);
+    assert!(env.events().all().is_empty());
+}
+
+#[test]
+fn test_event_topics_support_filtering() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, sender, receiver) = setup_event_with_wallets(&env, &client);
+
+    client.event_payment(&event_id, &sender, &receiver, &200, &None);
+
+    // Indexers can filter payments by event, sender or recipient from the topics alone
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> = soroban_sdk::vec![
+        &env,
+        Symbol::new(&env, "payment_event").into_val(&env),
+        event_id.into_val(&env),
+        sender.into_val(&env),
+        receiver.into_val(&env),
+    ];
+    assert_eq!(topics, expected);
+}
+
+#[test]
+fn test_error_codes_are_stable() {
+    // Clients match on the numeric codes: existing codes never move, new errors are appended
+    assert_eq!(ContractError::OrganizerCannotRegister as u32, 15);
+    assert_eq!(ContractError::SpendingLimitExceeded as u32, 16);
+    assert_eq!(ContractError::RecordNotFound as u32, 18);
+    assert_eq!(ContractError::InvalidState as u32, 22);
+    assert_eq!(ContractError::Unauthorized as u32, 25);
+    assert_eq!(ContractError::NonceAlreadyUsed as u32, 40);
+}
@@ -0,0 +1,259 @@
+#![cfg(test)]
+
+// Balance deltas of every party for each fee mode, with a real Stellar asset token
+
+use crate::{ContractError, EventPaymentContract, EventPaymentContractClient, FeeMode};
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+
+struct Parties<'a> {
+    client: EventPaymentContractClient<'a>,
+    token: TokenClient<'a>,
+    admin: Address,
+    organizer: Address,
+    sender: Address,
+    recipient: Address,
+    sponsor: Address,
+    event_id: u64,
+}
+
+fn setup_parties<'a>() -> Parties<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EventPaymentContract, ());
+    let client = EventPaymentContractClient::new(&env, &contract_id);
+
+    let token_address = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let token = TokenClient::new(&env, &token_address);
+    let token_admin = StellarAssetClient::new(&env, &token_address);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &500, &token_address);
+
+    let organizer = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    for account in [&organizer, &sender, &sponsor] {
+        token_admin.mint(account, &10_000);
+    }
+
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Fee Modes"), &None);
+    client.register_wallet_for_event(&event_id, &sender);
+    client.register_wallet_for_event(&event_id, &recipient);
+
+    Parties { client, token, admin, organizer, sender, recipient, sponsor, event_id }
+}
+
+impl Parties<'_> {
+    // Balances of sender, recipient, organizer, sponsor, admin and contract
+    fn balances(&self) -> [i128; 6] {
+        [&self.sender, &self.recipient, &self.organizer, &self.sponsor, &self.admin, &self.client.address]
+            .map(|account| self.token.balance(account))
+    }
+
+    fn deltas(&self, before: [i128; 6]) -> [i128; 6] {
+        let after = self.balances();
+        [0, 1, 2, 3, 4, 5].map(|i| after[i] - before[i])
+    }
+}
+
+#[test]
+fn test_event_payment_recipient_pays() {
+    let p = setup_parties();
+    assert_eq!(p.client.get_event_fee_mode(&p.event_id), FeeMode::Recipient);
+
+    let before = p.balances();
+    let payment_id = p.client.event_payment(&p.event_id, &p.sender, &p.recipient, &1000, &None);
+
+    // sender, recipient, organizer, sponsor, admin, contract
+    assert_eq!(p.deltas(before), [-1000, 950, 0, 0, 0, 50]);
+    assert_eq!(p.client.get_event_fees(&p.event_id), 50);
+    assert_eq!(p.client.get_payment(&payment_id).fee_payer, p.recipient);
+}
+
+#[test]
+fn test_event_payment_sender_pays() {
+    let p = setup_parties();
+    p.client.set_event_fee_mode(&p.event_id, &FeeMode::Sender);
+
+    let before = p.balances();
+    let payment_id = p.client.event_payment(&p.event_id, &p.sender, &p.recipient, &1000, &None);
+
+    assert_eq!(p.deltas(before), [-1050, 1000, 0, 0, 0, 50]);
+    assert_eq!(p.client.get_event_fees(&p.event_id), 50);
+    assert_eq!(p.client.get_event(&p.event_id).total_volume, 1000);
+
+    let record = p.client.get_payment(&payment_id);
+    assert_eq!(record.fee_payer, p.sender);
+    assert_eq!(record.fee_amount, 50);
+
+    // The fee counts toward the sender's balance check
+    let result = p.client.try_event_payment(&p.event_id, &p.sender, &p.recipient, &8600, &None);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientBalanceFromSender)));
+}
+
+#[test]
+fn test_event_payment_organizer_sponsors() {
+    let p = setup_parties();
+    p.client.set_event_fee_mode(&p.event_id, &FeeMode::Sponsor);
+
+    // The organizer sponsors fees through their allowance
+    let result = p.client.try_event_payment(&p.event_id, &p.sender, &p.recipient, &1000, &None);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientAllowance)));
+    p.client.increase_event_allowance(&p.event_id, &60);
+
+    let before = p.balances();
+    let payment_id = p.client.event_payment(&p.event_id, &p.sender, &p.recipient, &1000, &None);
+
+    assert_eq!(p.deltas(before), [-1000, 1000, -50, 0, 0, 50]);
+    assert_eq!(p.client.get_event_fees(&p.event_id), 50);
+    assert_eq!(p.client.get_payment(&payment_id).fee_payer, p.organizer);
+    assert_eq!(p.token.allowance(&p.organizer, &p.client.address), 10);
+}
+
+#[test]
+fn test_third_party_fee_paid_by_sponsor() {
+    let p = setup_parties();
+
+    let before = p.balances();
+    let payment_id = p.client.payment_with_third_party_fee(&p.sender, &p.recipient, &p.sponsor, &1000, &None);
+
+    // Recipient gets the full amount, the sponsor pays the fee to the protocol admin
+    assert_eq!(p.deltas(before), [-1000, 1000, 0, -50, 50, 0]);
+
+    let record = p.client.get_payment(&payment_id);
+    assert_eq!(record.fee_payer, p.sponsor);
+    assert_eq!(record.fee_amount, 50);
+}
+
+#[test]
+fn test_auth_fee_payer_uses_allowance() {
+    let p = setup_parties();
+
+    let result = p.client.try_payment_with_auth_fee_payer(&p.sender, &p.recipient, &p.sponsor, &1000, &None);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientAllowance)));
+
+    p.client.authorize_fee_payments(&p.sponsor, &80);
+    let before = p.balances();
+    p.client.payment_with_auth_fee_payer(&p.sender, &p.recipient, &p.sponsor, &1000, &None);
+
+    // The fee is pulled from the sponsor's budget; nothing stays in the contract
+    assert_eq!(p.deltas(before), [-1000, 1000, 0, -50, 50, 0]);
+    assert_eq!(p.client.get_fee_budget(&p.sponsor), 30);
+    assert_eq!(p.token.allowance(&p.sponsor, &p.client.address), 30);
+    assert_eq!(p.client.check_solvency().surplus, 0);
+
+    // Revoking withdraws what's left of the budget
+    p.client.revoke_fee_authorization(&p.sponsor);
+    assert_eq!(p.client.get_fee_budget(&p.sponsor), 0);
+    assert_eq!(p.token.allowance(&p.sponsor, &p.client.address), 0);
+}
+
+#[test]
+fn test_auth_fee_payer_is_limited_to_its_fee_budget() {
+    let p = setup_parties();
+
+    // The sponsor's allowance also backs event fees, but only the fee budget pays for strangers
+    p.token.approve(&p.sponsor, &p.client.address, &5000, &1000);
+    let stranger = Address::generate(&p.token.env);
+    let result = p.client.try_payment_with_auth_fee_payer(&p.sender, &stranger, &p.sponsor, &1000, &None);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientAllowance)));
+
+    p.client.authorize_fee_payments(&p.sponsor, &60);
+    assert_eq!(p.token.allowance(&p.sponsor, &p.client.address), 5060);
+    p.client.payment_with_auth_fee_payer(&p.sender, &stranger, &p.sponsor, &1000, &None);
+    let result = p.client.try_payment_with_auth_fee_payer(&p.sender, &stranger, &p.sponsor, &1000, &None);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientAllowance)));
+    assert_eq!(p.token.allowance(&p.sponsor, &p.client.address), 5010);
+}
+
+#[test]
+fn test_general_payment_fee_modes() {
+    let p = setup_parties();
+
+    // Sender mode: the sender is the fee_payer and pays the fee on top
+    let before = p.balances();
+    let payment_id = p.client.payment_with_third_party_fee(&p.sender, &p.recipient, &p.sender, &1000, &None);
+    assert_eq!(p.deltas(before), [-1050, 1000, 0, 0, 50, 0]);
+    assert_eq!(p.client.get_payment(&payment_id).fee_payer, p.sender);
+
+    // Recipient mode: the fee comes out of the amount, no budget needed
+    let before = p.balances();
+    let payment_id = p.client.payment_with_auth_fee_payer(&p.sender, &p.recipient, &p.recipient, &1000, &None);
+    assert_eq!(p.deltas(before), [-1000, 950, 0, 0, 50, 0]);
+    assert_eq!(p.client.get_payment(&payment_id).fee_payer, p.recipient);
+
+    // Sender mode without the sponsor's budget either
+    let before = p.balances();
+    p.client.payment_with_auth_fee_payer(&p.sender, &p.recipient, &p.sender, &1000, &None);
+    assert_eq!(p.deltas(before), [-1050, 1000, 0, 0, 50, 0]);
+    assert_eq!(p.client.check_solvency().surplus, 0);
+}
+
+#[test]
+fn test_escrow_recipient_pays() {
+    let p = setup_parties();
+
+    let before = p.balances();
+    let escrow_id = p.client.escrow_payment(&p.event_id, &p.sender, &p.recipient, &1000, &3600, &None);
+    assert_eq!(p.deltas(before), [-1000, 0, 0, 0, 0, 1000]);
+
+    let payment_id = p.client.confirm_escrow(&escrow_id);
+    assert_eq!(p.deltas(before), [-1000, 950, 0, 0, 0, 50]);
+    assert_eq!(p.client.get_payment(&payment_id).fee_payer, p.recipient);
+    assert_eq!(p.client.check_solvency().surplus, 0);
+}
+
+#[test]
+fn test_escrow_sender_pays() {
+    let p = setup_parties();
+    p.client.set_event_fee_mode(&p.event_id, &FeeMode::Sender);
+
+    // The fee is held with the amount; a refund returns both to the buyer
+    let before = p.balances();
+    let refunded = p.client.escrow_payment(&p.event_id, &p.sender, &p.recipient, &1000, &3600, &None);
+    assert_eq!(p.deltas(before), [-1050, 0, 0, 0, 0, 1050]);
+    p.client.dispute_escrow(&refunded);
+    p.client.resolve_escrow_dispute(&refunded, &true);
+    assert_eq!(p.deltas(before), [0; 6]);
+
+    // Switching modes later doesn't change what an open escrow settles with
+    let escrow_id = p.client.escrow_payment(&p.event_id, &p.sender, &p.recipient, &1000, &3600, &None);
+    p.client.set_event_fee_mode(&p.event_id, &FeeMode::Recipient);
+    let payment_id = p.client.confirm_escrow(&escrow_id);
+    assert_eq!(p.deltas(before), [-1050, 1000, 0, 0, 0, 50]);
+
+    let record = p.client.get_payment(&payment_id);
+    assert_eq!(record.fee_payer, p.sender);
+    assert_eq!(record.fee_amount, 50);
+    assert_eq!(p.client.check_solvency().surplus, 0);
+}
+
+#[test]
+fn test_escrow_organizer_sponsors() {
+    let p = setup_parties();
+    p.client.set_event_fee_mode(&p.event_id, &FeeMode::Sponsor);
+
+    // The organizer's allowance backs the fee when the escrow is opened
+    let result = p.client.try_escrow_payment(&p.event_id, &p.sender, &p.recipient, &1000, &3600, &None);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientAllowance)));
+    p.client.increase_event_allowance(&p.event_id, &100);
+
+    let before = p.balances();
+    let refunded = p.client.escrow_payment(&p.event_id, &p.sender, &p.recipient, &1000, &3600, &None);
+    assert_eq!(p.deltas(before), [-1000, 0, -50, 0, 0, 1050]);
+    p.client.dispute_escrow(&refunded);
+    p.client.resolve_escrow_dispute(&refunded, &true);
+    assert_eq!(p.deltas(before), [0; 6]);
+
+    let escrow_id = p.client.escrow_payment(&p.event_id, &p.sender, &p.recipient, &1000, &3600, &None);
+    let payment_id = p.client.confirm_escrow(&escrow_id);
+    assert_eq!(p.deltas(before), [-1000, 1000, -50, 0, 0, 50]);
+    assert_eq!(p.client.get_payment(&payment_id).fee_payer, p.organizer);
+    assert_eq!(p.client.check_solvency().surplus, 0);
+}
@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+// Fuzz-style checks of the fee function under every rounding mode, plus the
+// admin setter for the fee policy
+use crate::simulation::Rng;
+use crate::{ContractError, EventPaymentContract, EventPaymentContractClient, FeePolicy, RoundingMode};
+use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Address, Env, String};
+
+fn policy(rounding: RoundingMode, min_fee: i128) -> FeePolicy {
+    FeePolicy { rounding, min_fee }
+}
+
+fn fee(amount: i128, rate: u32, rounding: RoundingMode, min_fee: i128) -> Result<i128, ContractError> {
+    EventPaymentContract::fee_with_policy(amount, rate, &policy(rounding, min_fee))
+}
+
+#[test]
+fn test_rounding_modes_on_known_values() {
+    // 150 × 500 / 10000 = 7.5
+    assert_eq!(fee(150, 500, RoundingMode::Floor, 0), Ok(7));
+    assert_eq!(fee(150, 500, RoundingMode::Ceil, 0), Ok(8));
+    assert_eq!(fee(150, 500, RoundingMode::HalfEven, 0), Ok(8));
+    // 250 × 300 / 10000 = 7.5 → even neighbour is 8, 6.5 → 6
+    assert_eq!(fee(250, 300, RoundingMode::HalfEven, 0), Ok(8));
+    assert_eq!(fee(130, 500, RoundingMode::HalfEven, 0), Ok(6));
+    // Exact fees are never rounded
+    assert_eq!(fee(200, 500, RoundingMode::Ceil, 0), Ok(10));
+
+    // The minimum fee applies to non-zero rates only and never exceeds the amount
+    assert_eq!(fee(10, 500, RoundingMode::Floor, 3), Ok(3));
+    assert_eq!(fee(2, 500, RoundingMode::Floor, 3), Ok(2));
+    assert_eq!(fee(1000, 0, RoundingMode::Ceil, 3), Ok(0));
+}
+
+#[test]
+fn test_fee_properties_hold_for_random_inputs() {
+    let mut rng = Rng(44);
+
+    for _ in 0..5000 {
+        // Mix small amounts (where rounding matters) with large ones
+        let amount = match rng.below(3) {
+            0 => rng.below(1000) as i128,
+            1 => rng.below(1 << 40) as i128,
+            _ => (rng.next() as i128) << rng.below(50),
+        };
+        let rate = rng.below(1001) as u32;
+        let min_fee = rng.below(50) as i128;
+
+        let floor = fee(amount, rate, RoundingMode::Floor, 0).unwrap();
+        let half_even = fee(amount, rate, RoundingMode::HalfEven, 0).unwrap();
+        let ceil = fee(amount, rate, RoundingMode::Ceil, 0).unwrap();
+
+        assert!(floor <= half_even && half_even <= ceil, "amount {amount}, rate {rate}");
+        assert!(ceil - floor <= 1, "amount {amount}, rate {rate}");
+        assert_eq!(floor, amount * rate as i128 / 10000);
+        assert!(ceil <= amount);
+
+        for rounding in [RoundingMode::Floor, RoundingMode::Ceil, RoundingMode::HalfEven] {
+            let charged = fee(amount, rate, rounding, min_fee).unwrap();
+            assert!(charged >= 0 && charged <= amount);
+            if rate > 0 {
+                assert!(charged >= min_fee.min(amount), "amount {amount}, rate {rate}, min {min_fee}");
+            } else {
+                assert_eq!(charged, 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fee_overflow_is_an_error() {
+    let mut rng = Rng(4400);
+
+    for _ in 0..1000 {
+        let rate = 2 + rng.below(999) as u32;
+        let amount = i128::MAX - rng.below(1 << 40) as i128;
+        for rounding in [RoundingMode::Floor, RoundingMode::Ceil, RoundingMode::HalfEven] {
+            assert_eq!(fee(amount, rate, rounding, 0), Err(ContractError::ArithmeticOverflow));
+        }
+    }
+    assert_eq!(fee(-1, 500, RoundingMode::Floor, 0), Err(ContractError::AmountMustBePositive));
+}
+
+#[test]
+fn test_set_fee_policy_changes_event_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EventPaymentContract, ());
+    let client = EventPaymentContractClient::new(&env, &contract_id);
+    let token_address = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &500, &token_address);
+    assert_eq!(client.get_fee_policy(), policy(RoundingMode::Floor, 0));
+
+    let organizer = Address::generate(&env);
+    let (from, to) = (Address::generate(&env), Address::generate(&env));
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Rounding"), &None);
+    client.register_wallet_for_event(&event_id, &from);
+    client.register_wallet_for_event(&event_id, &to);
+    StellarAssetClient::new(&env, &token_address).mint(&from, &1000);
+
+    // Only the admin can change the policy, and the minimum can't be negative
+    let result = client.try_set_fee_policy(&organizer, &policy(RoundingMode::Ceil, 0));
+    assert_eq!(result, Err(Ok(ContractError::NotEventOrganizer)));
+    let result = client.try_set_fee_policy(&admin, &policy(RoundingMode::Ceil, -1));
+    assert_eq!(result, Err(Ok(ContractError::AmountMustBePositive)));
+
+    client.event_payment(&event_id, &from, &to, &150, &None);
+    assert_eq!(client.get_event_fees(&event_id), 7);
+
+    client.set_fee_policy(&admin, &policy(RoundingMode::Ceil, 0));
+    client.event_payment(&event_id, &from, &to, &150, &None);
+    assert_eq!(client.get_event_fees(&event_id), 7 + 8);
+
+    client.set_fee_policy(&admin, &policy(RoundingMode::HalfEven, 20));
+    let payment_id = client.event_payment(&event_id, &from, &to, &150, &None);
+    assert_eq!(client.get_payment(&payment_id).fee_amount, 20);
+    assert_eq!(client.get_event_fees(&event_id), 7 + 8 + 20);
+}
@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+// Fiat-priced events against a mock SEP-40 oracle: conversion, staleness and
+// slippage bounds on ticket sales and event payments
+use crate::{
+    Asset, ContractError, EventPaymentContract, EventPaymentContractClient, FiatConverted, FiatPricing, PriceData,
+};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String, Symbol,
+};
+
+// Mock SEP-40 oracle quoting prices in USD with 14 decimals
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn base(_env: Env) -> Asset {
+        Asset::Other(symbol_short!("USD"))
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        14
+    }
+
+    pub fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        env.storage().temporary().get(&asset)
+    }
+
+    pub fn set_price(env: Env, asset: Asset, price: i128, timestamp: u64) {
+        env.storage().temporary().set(&asset, &PriceData { price, timestamp });
+    }
+}
+
+const CENT: i128 = 1_000_000_000_000; // 0.01 USD at 14 decimals
+
+struct Setup<'a> {
+    env: Env,
+    client: EventPaymentContractClient<'a>,
+    oracle: MockOracleClient<'a>,
+    token: Address,
+    organizer: Address,
+    event_id: u64,
+    buyer: Address,
+    vendor: Address,
+}
+
+/// Token at 0.20 USD, BRL at 0.19 USD, ledger time 10_000
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 10_000);
+
+    let client = EventPaymentContractClient::new(&env, &env.register(EventPaymentContract, ()));
+    let oracle = MockOracleClient::new(&env, &env.register(MockOracle, ()));
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    client.initialize(&Address::generate(&env), &500, &token);
+
+    oracle.set_price(&Asset::Stellar(token.clone()), &(20 * CENT), &10_000);
+    oracle.set_price(&Asset::Other(symbol_short!("BRL")), &(19 * CENT), &9_900);
+
+    let organizer = Address::generate(&env);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Feira"), &None);
+    let (buyer, vendor) = (Address::generate(&env), Address::generate(&env));
+    client.register_wallet_for_event(&event_id, &buyer);
+    client.register_wallet_for_event(&event_id, &vendor);
+    StellarAssetClient::new(&env, &token).mint(&buyer, &100_000);
+
+    Setup { env, client, oracle, token, organizer, event_id, buyer, vendor }
+}
+
+fn pricing(s: &Setup, currency: Symbol, max_price_age: u64) -> FiatPricing {
+    FiatPricing { oracle: s.oracle.address.clone(), currency, max_price_age }
+}
+
+fn assert_published(env: &Env, contract_id: &Address, expected: &impl soroban_sdk::Event) {
+    let found = env.events().all().iter().any(|(address, topics, data)| {
+        address == *contract_id
+            && topics == expected.topics(env)
+            && soroban_sdk::vec![env, data] == soroban_sdk::vec![env, expected.data(env)]
+    });
+    assert!(found, "event not published");
+}
+
+#[test]
+fn test_fiat_ticket_price_with_slippage_bound() {
+    let s = setup();
+    let token = TokenClient::new(&s.env, &s.token);
+
+    // Tickets cost 1000 BRL units: 1000 × 0.19 / 0.20 = 950 tokens
+    s.client.set_fiat_pricing(&s.event_id, &Some(pricing(&s, symbol_short!("BRL"), 300)));
+    s.client.set_ticket_price(&s.event_id, &1000);
+    assert_eq!(s.client.get_ticket_price(&s.event_id), Some(1000));
+    assert_eq!(s.client.quote_fiat(&s.event_id, &1000), 950);
+
+    let result = s.client.try_purchase_ticket_with_max_price(&s.event_id, &s.buyer, &None, &949);
+    assert_eq!(result, Err(Ok(ContractError::SlippageExceeded)));
+
+    let ticket_id = s.client.purchase_ticket_with_max_price(&s.event_id, &s.buyer, &None, &950);
+    assert_published(&s.env, &s.client.address, &FiatConverted {
+        event_id: s.event_id,
+        currency: symbol_short!("BRL"),
+        fiat_amount: 1000,
+        amount: 950,
+    });
+
+    // Face value is kept in tokens so resale caps stay comparable
+    assert_eq!(s.client.get_ticket(&ticket_id).face_value, 950);
+    assert_eq!(token.balance(&s.organizer), 950);
+    assert_eq!(s.client.get_event(&s.event_id).total_volume, 950);
+
+    // The token doubles in value: the same ticket now costs half as many tokens
+    s.oracle.set_price(&Asset::Stellar(s.token.clone()), &(40 * CENT), &10_000);
+    s.client.purchase_ticket(&s.event_id, &s.buyer, &None);
+    assert_eq!(token.balance(&s.organizer), 950 + 475);
+}
+
+#[test]
+fn test_fiat_event_payment() {
+    let s = setup();
+    let token = TokenClient::new(&s.env, &s.token);
+    s.client.set_fiat_pricing(&s.event_id, &Some(pricing(&s, symbol_short!("BRL"), 300)));
+
+    // 333 × 0.19 / 0.20 = 316.35, rounded up so the vendor isn't short-changed
+    assert_eq!(s.client.quote_fiat(&s.event_id, &333), 317);
+    let result = s.client.try_event_payment_fiat(&s.event_id, &s.buyer, &s.vendor, &333, &316, &None);
+    assert_eq!(result, Err(Ok(ContractError::SlippageExceeded)));
+
+    let payment_id = s.client.event_payment_fiat(&s.event_id, &s.buyer, &s.vendor, &333, &320, &None);
+    let payment = s.client.get_payment(&payment_id);
+    assert_eq!((payment.amount, payment.fee_amount), (317, 15));
+    assert_eq!(token.balance(&s.buyer), 100_000 - 317);
+    assert_eq!(token.balance(&s.vendor), 317 - 15);
+    assert_eq!(s.client.get_event_fees(&s.event_id), 15);
+
+    // Priced in the oracle's base currency: only the token price is needed
+    s.client.set_fiat_pricing(&s.event_id, &Some(pricing(&s, symbol_short!("USD"), 300)));
+    assert_eq!(s.client.quote_fiat(&s.event_id, &100), 500);
+
+    // Back to tokens: fiat payments are no longer possible
+    s.client.set_fiat_pricing(&s.event_id, &None);
+    assert_eq!(s.client.get_fiat_pricing(&s.event_id), None);
+    let result = s.client.try_event_payment_fiat(&s.event_id, &s.buyer, &s.vendor, &100, &1000, &None);
+    assert_eq!(result, Err(Ok(ContractError::RecordNotFound)));
+}
+
+#[test]
+fn test_stale_or_missing_prices_are_rejected() {
+    let s = setup();
+    s.client.set_fiat_pricing(&s.event_id, &Some(pricing(&s, symbol_short!("BRL"), 300)));
+    s.client.set_ticket_price(&s.event_id, &1000);
+
+    // The BRL price (timestamp 9_900) is still fresh at 10_200, stale one second later
+    s.env.ledger().with_mut(|l| l.timestamp = 10_200);
+    assert_eq!(s.client.quote_fiat(&s.event_id, &1000), 950);
+    s.env.ledger().with_mut(|l| l.timestamp = 10_201);
+    assert_eq!(s.client.try_quote_fiat(&s.event_id, &1000), Err(Ok(ContractError::OraclePriceStale)));
+    let result = s.client.try_purchase_ticket(&s.event_id, &s.buyer, &None);
+    assert_eq!(result, Err(Ok(ContractError::OraclePriceStale)));
+    let result = s.client.try_event_payment_fiat(&s.event_id, &s.buyer, &s.vendor, &100, &1000, &None);
+    assert_eq!(result, Err(Ok(ContractError::OraclePriceStale)));
+
+    // Unknown currencies and non-positive prices are treated like stale ones
+    s.client.set_fiat_pricing(&s.event_id, &Some(pricing(&s, symbol_short!("EUR"), 300)));
+    assert_eq!(s.client.try_quote_fiat(&s.event_id, &1000), Err(Ok(ContractError::OraclePriceStale)));
+    s.oracle.set_price(&Asset::Other(symbol_short!("EUR")), &0, &10_201);
+    assert_eq!(s.client.try_quote_fiat(&s.event_id, &1000), Err(Ok(ContractError::OraclePriceStale)));
+
+    // No ticket was sold and nothing was charged
+    assert_eq!(s.client.get_event(&s.event_id).total_volume, 0);
+    assert_eq!(s.client.balance(&s.buyer), 0);
+}
+
+#[test]
+fn test_fiat_pricing_validation_and_bounds() {
+    let s = setup();
+
+    let result = s.client.try_set_fiat_pricing(&s.event_id, &Some(pricing(&s, symbol_short!("BRL"), 0)));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+    assert_eq!(s.client.try_quote_fiat(&s.event_id, &1000), Err(Ok(ContractError::RecordNotFound)));
+
+    // Negative fiat amounts and conversions that overflow are errors, not panics
+    s.client.set_fiat_pricing(&s.event_id, &Some(pricing(&s, symbol_short!("BRL"), 300)));
+    assert_eq!(s.client.try_quote_fiat(&s.event_id, &-1), Err(Ok(ContractError::AmountMustBePositive)));
+    assert_eq!(s.client.try_quote_fiat(&s.event_id, &i128::MAX), Err(Ok(ContractError::ArithmeticOverflow)));
+
+    // Token-priced events ignore the bound unless the price exceeds it
+    s.client.set_fiat_pricing(&s.event_id, &None);
+    s.client.set_ticket_price(&s.event_id, &700);
+    let result = s.client.try_purchase_ticket_with_max_price(&s.event_id, &s.buyer, &None, &699);
+    assert_eq!(result, Err(Ok(ContractError::SlippageExceeded)));
+    s.client.purchase_ticket_with_max_price(&s.event_id, &s.buyer, &None, &700);
+    assert_eq!(s.client.get_event(&s.event_id).total_volume, 700);
+}
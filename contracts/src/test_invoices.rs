@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+// Payment requests (invoices) created by vendors and settled by id
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, assert_last_event, MockTokenClient};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, String};
+
+#[test]
+fn test_invoice_lifecycle() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, payer, vendor) = setup_event_with_wallets(&env, &client);
+    let token = MockTokenClient::new(&env, &token_address);
+    let memo = String::from_str(&env, "Table 12 - 2 beers");
+
+    let invoice_id = client.create_invoice(&event_id, &vendor, &200, &memo, &3600);
+    assert_eq!(invoice_id, 1);
+    assert_last_event(&env, &client.address, &InvoiceCreated {
+        invoice_id,
+        event_id,
+        payee: vendor.clone(),
+        amount: 200,
+        memo: memo.clone(),
+        expires_at: 3600,
+    });
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Open);
+    assert_eq!(invoice.payer, None);
+
+    client.pay_invoice(&invoice_id, &payer);
+    assert_last_event(&env, &client.address, &InvoicePaid {
+        invoice_id,
+        event_id,
+        payer: payer.clone(),
+        payee: vendor.clone(),
+        amount: 200,
+    });
+
+    // Same fee flow as event_payment
+    assert_eq!(token.balance(&vendor), 190);
+    assert_eq!(client.get_event_fees(&event_id), 10);
+    assert_eq!(client.get_event(&event_id).total_volume, 200);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(payer.clone()));
+
+    // Cannot be paid or cancelled twice
+    assert_eq!(client.try_pay_invoice(&invoice_id, &payer), Err(Ok(ContractError::InvalidState)));
+    assert_eq!(client.try_cancel_invoice(&invoice_id), Err(Ok(ContractError::InvalidState)));
+}
+
+#[test]
+fn test_invoice_expiry_and_cancellation() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, payer, vendor) = setup_event_with_wallets(&env, &client);
+    let memo = String::from_str(&env, "Merch");
+
+    // Validation
+    let result = client.try_create_invoice(&event_id, &vendor, &0, &memo, &3600);
+    assert_eq!(result, Err(Ok(ContractError::AmountMustBePositive)));
+    let result = client.try_create_invoice(&event_id, &vendor, &100, &memo, &0);
+    assert_eq!(result, Err(Ok(ContractError::Expired)));
+    let long_memo = String::from_str(&env, "This memo is definitely longer than sixty-four characters, so it fails");
+    let result = client.try_create_invoice(&event_id, &vendor, &100, &long_memo, &3600);
+    assert_eq!(result, Err(Ok(ContractError::TextTooLong)));
+    let outsider = Address::generate(&env);
+    let result = client.try_create_invoice(&event_id, &outsider, &100, &memo, &3600);
+    assert_eq!(result, Err(Ok(ContractError::WalletNotRegistered)));
+
+    // Expired invoice cannot be paid
+    let expiring = client.create_invoice(&event_id, &vendor, &100, &memo, &3600);
+    env.ledger().with_mut(|l| l.timestamp = 3600);
+    assert_eq!(client.get_invoice(&expiring).status, InvoiceStatus::Expired);
+    assert_eq!(client.try_pay_invoice(&expiring, &payer), Err(Ok(ContractError::Expired)));
+
+    // Cancelled invoice cannot be paid
+    let cancelled = client.create_invoice(&event_id, &vendor, &100, &memo, &7200);
+    client.cancel_invoice(&cancelled);
+    assert_last_event(&env, &client.address, &InvoiceCancelled { invoice_id: cancelled, event_id });
+    assert_eq!(client.get_invoice(&cancelled).status, InvoiceStatus::Cancelled);
+    assert_eq!(client.try_pay_invoice(&cancelled, &payer), Err(Ok(ContractError::InvalidState)));
+
+    assert_eq!(client.try_get_invoice(&99), Err(Ok(ContractError::RecordNotFound)));
+}
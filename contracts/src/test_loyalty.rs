@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+// Loyalty points earned on payments and redeemed for vouchers or fee discounts
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, assert_last_event, MockTokenClient};
+use soroban_sdk::String;
+
+#[test]
+fn test_loyalty_points_earned_and_redeemed() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, attendee, vendor) = setup_event_with_wallets(&env, &client);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    // No points until the organizer configures an earn rate
+    client.event_payment(&event_id, &attendee, &vendor, &1000, &None);
+    assert_eq!(client.get_points(&event_id, &attendee), 0);
+
+    // 10 points per 100 spent, each point worth 0.5 tokens
+    client.set_loyalty_config(&event_id, &LoyaltyConfig { earn_rate_bps: 1000, point_value_bps: 5000 });
+    client.event_payment(&event_id, &attendee, &vendor, &1000, &None);
+    assert_last_event(&env, &client.address, &PointsEarned {
+        event_id,
+        wallet: attendee.clone(),
+        points: 100,
+        balance: 100,
+    });
+    assert_eq!(client.get_event_fees(&event_id), 100);
+
+    assert_eq!(
+        client.try_redeem_points(&event_id, &attendee, &101, &LoyaltyReward::Voucher),
+        Err(Ok(ContractError::InsufficientPoints))
+    );
+
+    // Voucher: 40 points = 20 tokens paid from the event fees
+    let before = token.balance(&attendee);
+    assert_eq!(client.redeem_points(&event_id, &attendee, &40, &LoyaltyReward::Voucher), 20);
+    assert_eq!(token.balance(&attendee), before + 20);
+    assert_eq!(client.get_event_fees(&event_id), 80);
+    assert_eq!(client.get_points(&event_id, &attendee), 60);
+
+    // Fee discount: 60 points = 30 tokens of credit off the fees the attendee pays, so it
+    // needs an event where the sender pays the fee
+    assert_eq!(
+        client.try_redeem_points(&event_id, &attendee, &60, &LoyaltyReward::FeeDiscount),
+        Err(Ok(ContractError::LoyaltyRedemptionDisabled))
+    );
+    client.set_event_fee_mode(&event_id, &FeeMode::Sender);
+    client.redeem_points(&event_id, &attendee, &60, &LoyaltyReward::FeeDiscount);
+    assert_eq!(client.get_fee_credit(&event_id, &attendee), 30);
+
+    // The 20 fee on top is refunded to the attendee from the credit
+    let (before, vendor_before) = (token.balance(&attendee), token.balance(&vendor));
+    let payment_id = client.event_payment(&event_id, &attendee, &vendor, &400, &None);
+    assert_eq!(token.balance(&attendee), before - 400);
+    assert_eq!(token.balance(&vendor), vendor_before + 400);
+    assert_eq!(client.get_payment(&payment_id).fee_amount, 0);
+    assert_eq!(client.get_fee_credit(&event_id, &attendee), 10);
+    assert_eq!(client.get_event_fees(&event_id), 80);
+    assert_eq!(client.get_points(&event_id, &attendee), 40);
+
+    // Redemption can be switched off, and vouchers can't exceed collected fees
+    client.set_loyalty_config(&event_id, &LoyaltyConfig { earn_rate_bps: 1000, point_value_bps: 0 });
+    assert_eq!(
+        client.try_redeem_points(&event_id, &attendee, &40, &LoyaltyReward::Voucher),
+        Err(Ok(ContractError::LoyaltyRedemptionDisabled))
+    );
+    client.set_loyalty_config(&event_id, &LoyaltyConfig { earn_rate_bps: 10000, point_value_bps: 5000 });
+    client.event_payment(&event_id, &attendee, &vendor, &1000, &None);
+    assert_eq!(client.get_event_fees(&event_id), 80 + 50 - 10);
+    assert_eq!(
+        client.try_redeem_points(&event_id, &attendee, &1000, &LoyaltyReward::Voucher),
+        Err(Ok(ContractError::InsufficientEventFees))
+    );
+}
+
+#[test]
+fn test_fee_credit_only_covers_fees_the_payer_pays() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, organizer, attendee, vendor) = setup_event_with_wallets(&env, &client);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    client.set_loyalty_config(&event_id, &LoyaltyConfig { earn_rate_bps: 1000, point_value_bps: 10000 });
+    client.event_payment(&event_id, &attendee, &vendor, &1000, &None);
+    client.set_event_fee_mode(&event_id, &FeeMode::Sender);
+    client.redeem_points(&event_id, &attendee, &20, &LoyaltyReward::FeeDiscount);
+
+    // Sender mode: the attendee paid the fee on top, so the discount comes back to them
+    let (before, vendor_before) = (token.balance(&attendee), token.balance(&vendor));
+    let payment_id = client.event_payment(&event_id, &attendee, &vendor, &200, &None);
+    assert_eq!(client.get_payment(&payment_id).fee_amount, 0);
+    assert_eq!(token.balance(&attendee), before - 200);
+    assert_eq!(token.balance(&vendor), vendor_before + 200);
+    assert_eq!(client.get_fee_credit(&event_id, &attendee), 10);
+
+    // Sponsor mode: the organizer pays the full fee and the attendee's credit is kept
+    client.set_event_fee_mode(&event_id, &FeeMode::Sponsor);
+    client.increase_event_allowance(&event_id, &10);
+    let (before, organizer_before) = (token.balance(&attendee), token.balance(&organizer));
+    let payment_id = client.event_payment(&event_id, &attendee, &vendor, &200, &None);
+    assert_eq!(client.get_payment(&payment_id).fee_amount, 10);
+    assert_eq!(token.balance(&attendee), before - 200);
+    assert_eq!(token.balance(&organizer), organizer_before - 10);
+    assert_eq!(client.get_fee_credit(&event_id, &attendee), 10);
+
+    // Recipient mode: the vendor pays the full fee and the credit is kept too
+    client.set_event_fee_mode(&event_id, &FeeMode::Recipient);
+    let vendor_before = token.balance(&vendor);
+    let payment_id = client.event_payment(&event_id, &attendee, &vendor, &200, &None);
+    assert_eq!(client.get_payment(&payment_id).fee_amount, 10);
+    assert_eq!(token.balance(&vendor), vendor_before + 190);
+    assert_eq!(client.get_fee_credit(&event_id, &attendee), 10);
+}
+
+#[test]
+fn test_loyalty_points_are_scoped_to_their_event() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, organizer, attendee, vendor) = setup_event_with_wallets(&env, &client);
+
+    // Rates are bounded so a payment can't mint more value than it spent
+    assert_eq!(
+        client.try_set_loyalty_config(&event_id, &LoyaltyConfig { earn_rate_bps: 100000, point_value_bps: 5000 }),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_loyalty_config(&event_id, &LoyaltyConfig { earn_rate_bps: 1000, point_value_bps: 10001 }),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+
+    client.set_loyalty_config(&event_id, &LoyaltyConfig { earn_rate_bps: 10000, point_value_bps: 10000 });
+    client.event_payment(&event_id, &attendee, &vendor, &500, &None);
+    assert_eq!(client.get_points(&event_id, &attendee), 500);
+
+    // Points earned in one event can't drain another event's fees
+    let other_event = client.create_event(&organizer, &String::from_str(&env, "Other Festival"), &None);
+    client.register_wallet_for_event(&other_event, &attendee);
+    client.register_wallet_for_event(&other_event, &vendor);
+    client.set_loyalty_config(&other_event, &LoyaltyConfig { earn_rate_bps: 0, point_value_bps: 10000 });
+    client.event_payment(&other_event, &attendee, &vendor, &1000, &None);
+    assert_eq!(client.get_points(&other_event, &attendee), 0);
+    assert_eq!(
+        client.try_redeem_points(&other_event, &attendee, &50, &LoyaltyReward::Voucher),
+        Err(Ok(ContractError::InsufficientPoints))
+    );
+
+    // Oversized redemptions fail instead of overflowing
+    assert_eq!(
+        client.try_redeem_points(&event_id, &attendee, &i128::MAX, &LoyaltyReward::Voucher),
+        Err(Ok(ContractError::ArithmeticOverflow))
+    );
+}
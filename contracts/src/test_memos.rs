@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+// Payment memos stored in payment records and emitted with PaymentEvent
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, assert_last_event};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, String};
+
+#[test]
+fn test_payment_memo_recorded_and_emitted() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, sender, receiver) = setup_event_with_wallets(&env, &client);
+    let memo = Some(String::from_str(&env, "ORDER-4521"));
+
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let payment_id = client.event_payment(&event_id, &sender, &receiver, &200, &memo);
+    assert_last_event(&env, &client.address, &PaymentEvent {
+        payment_id,
+        event_id,
+        from: sender.clone(),
+        to: receiver.clone(),
+        fee_payer: receiver.clone(),
+        amount: 200,
+        fee_amount: 10,
+        fee_rate: 500,
+        memo: memo.clone(),
+    });
+
+    let record = client.get_payment(&payment_id);
+    assert_eq!(record, PaymentRecord {
+        id: payment_id,
+        event_id,
+        from: sender.clone(),
+        to: receiver.clone(),
+        fee_payer: receiver.clone(),
+        amount: 200,
+        fee_amount: 10,
+        fee_rate: 500,
+        memo,
+        timestamp: 1000,
+    });
+
+    // Memo is optional and ids are sequential
+    let second = client.event_payment(&event_id, &sender, &receiver, &100, &None);
+    assert_eq!(second, payment_id + 1);
+    assert_eq!(client.get_payment(&second).memo, None);
+
+    // Memo is bounded
+    let long_memo = Some(String::from_str(&env, "This memo is definitely longer than sixty-four characters, so it fails"));
+    let result = client.try_event_payment(&event_id, &sender, &receiver, &100, &long_memo);
+    assert_eq!(result, Err(Ok(ContractError::TextTooLong)));
+
+    assert_eq!(client.try_get_payment(&99), Err(Ok(ContractError::RecordNotFound)));
+}
+
+#[test]
+fn test_general_payments_memo() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let sender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let fee_payer = Address::generate(&env);
+
+    let memo = Some(String::from_str(&env, "INV-1"));
+    let payment_id = client.payment_with_third_party_fee(&sender, &receiver, &fee_payer, &200, &memo);
+    let record = client.get_payment(&payment_id);
+    assert_eq!(record.event_id, 0);
+    assert_eq!(record.fee_payer, fee_payer);
+    assert_eq!(record.memo, memo);
+
+    client.authorize_fee_payments(&fee_payer, &100);
+    let memo = Some(String::from_str(&env, "INV-2"));
+    let payment_id = client.payment_with_auth_fee_payer(&sender, &receiver, &fee_payer, &200, &memo);
+    let record = client.get_payment(&payment_id);
+    assert_eq!(record.event_id, 0);
+    assert_eq!(record.memo, memo);
+    assert_eq!(record.fee_amount, 10);
+}
+
+#[test]
+fn test_invoice_payment_uses_invoice_memo() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, payer, vendor) = setup_event_with_wallets(&env, &client);
+    let memo = String::from_str(&env, "Table 7");
+
+    let invoice_id = client.create_invoice(&event_id, &vendor, &100, &memo, &3600);
+    client.pay_invoice(&invoice_id, &payer);
+
+    // The invoice settlement is the first payment record
+    let record = client.get_payment(&1);
+    assert_eq!(record.memo, Some(memo));
+    assert_eq!(record.from, payer);
+    assert_eq!(record.to, vendor);
+}
@@ -0,0 +1,251 @@
+#![cfg(test)]
+
+// Path payments through a mock DEX router: the payer spends another token, the
+// contract swaps it into the event token and runs the normal fee logic
+use crate::{
+    ContractError, EventPaymentContract, EventPaymentContractClient, FeeMode, PathPaymentSwapped, SwapRoute,
+};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events as _},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, String, Vec,
+};
+
+// Mock router with a fixed rate per hop. It can misbehave: deliver less than it
+// reports (`shortfall`) or pull only part of the input (`input_used_bps`)
+#[contract]
+pub struct MockRouter;
+
+#[contractimpl]
+impl MockRouter {
+    pub fn set_rate(env: Env, token_in: Address, token_out: Address, rate_bps: i128) {
+        env.storage().instance().set(&(token_in, token_out), &rate_bps);
+    }
+
+    pub fn set_quirks(env: Env, shortfall: i128, input_used_bps: i128) {
+        env.storage().instance().set(&symbol_short!("quirks"), &(shortfall, input_used_bps));
+    }
+
+    pub fn swap_exact_tokens_for_tokens(
+        env: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128> {
+        assert!(deadline >= env.ledger().timestamp(), "expired");
+        let (shortfall, input_used_bps): (i128, i128) = env.storage().instance().get(&symbol_short!("quirks")).unwrap_or((0, 10000));
+
+        let mut amounts = vec![&env, amount_in];
+        for hop in 0..path.len() - 1 {
+            let rate: i128 = env.storage().instance().get(&(path.get(hop).unwrap(), path.get(hop + 1).unwrap())).unwrap();
+            amounts.push_back(amounts.last().unwrap() * rate / 10000);
+        }
+        let out = amounts.last().unwrap();
+        assert!(out >= amount_out_min, "insufficient output amount");
+
+        let router = env.current_contract_address();
+        let used = amount_in * input_used_bps / 10000;
+        TokenClient::new(&env, &path.first().unwrap()).transfer_from(&router, &to, &router, &used);
+        TokenClient::new(&env, &path.last().unwrap()).transfer(&router, &to, &(out - shortfall));
+        amounts
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: EventPaymentContractClient<'a>,
+    router: MockRouterClient<'a>,
+    admin: Address,
+    token: TokenClient<'a>,
+    eur: TokenClient<'a>,
+    xlm: Address,
+    event_id: u64,
+    payer: Address,
+    vendor: Address,
+}
+
+/// Event token at 1.2 per EUR; the router holds 1_000_000 event tokens; the payer holds 1000 EUR
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = EventPaymentContractClient::new(&env, &env.register(EventPaymentContract, ()));
+    let router = MockRouterClient::new(&env, &env.register(MockRouter, ()));
+    let issuer = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let eur = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let xlm = env.register_stellar_asset_contract_v2(issuer).address();
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &500, &token);
+    client.set_swap_router(&admin, &router.address);
+
+    router.set_rate(&eur, &token, &12000);
+    StellarAssetClient::new(&env, &token).mint(&router.address, &1_000_000);
+
+    let organizer = Address::generate(&env);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Global Village"), &None);
+    let (payer, vendor) = (Address::generate(&env), Address::generate(&env));
+    client.register_wallet_for_event(&event_id, &payer);
+    client.register_wallet_for_event(&event_id, &vendor);
+    StellarAssetClient::new(&env, &eur).mint(&payer, &1000);
+
+    Setup {
+        client,
+        router,
+        admin,
+        token: TokenClient::new(&env, &token),
+        eur: TokenClient::new(&env, &eur),
+        xlm,
+        event_id,
+        payer,
+        vendor,
+        env,
+    }
+}
+
+fn route(s: &Setup, amount_in: i128, min_out: i128, via: Vec<Address>) -> SwapRoute {
+    SwapRoute { send_token: s.eur.address.clone(), amount_in, min_out, via }
+}
+
+#[test]
+fn test_path_payment_swaps_then_charges_fee() {
+    let s = setup();
+
+    // 100 EUR → 120 tokens, then the usual 5% fee on the recipient
+    let payment_id = s.client.path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 100, 115, vec![&s.env]), &None);
+    let swapped = PathPaymentSwapped {
+        event_id: s.event_id,
+        from: s.payer.clone(),
+        send_token: s.eur.address.clone(),
+        amount_in: 100,
+        amount_out: 120,
+    };
+    let published = s.env.events().all().iter().any(|(address, topics, data)| {
+        address == s.client.address
+            && topics == soroban_sdk::Event::topics(&swapped, &s.env)
+            && vec![&s.env, data] == vec![&s.env, soroban_sdk::Event::data(&swapped, &s.env)]
+    });
+    assert!(published);
+
+    let payment = s.client.get_payment(&payment_id);
+    assert_eq!((payment.amount, payment.fee_amount), (120, 6));
+    assert_eq!(s.eur.balance(&s.payer), 900);
+    assert_eq!(s.eur.balance(&s.router.address), 100);
+    assert_eq!(s.token.balance(&s.payer), 0);
+    assert_eq!(s.token.balance(&s.vendor), 114);
+    assert_eq!(s.client.get_event_fees(&s.event_id), 6);
+    assert_eq!(s.client.get_event(&s.event_id).total_volume, 120);
+
+    // Nothing is left behind: the contract only holds the fee
+    assert_eq!(s.eur.balance(&s.client.address), 0);
+    assert_eq!(s.token.balance(&s.client.address), 6);
+    assert!(s.client.check_solvency().solvent);
+}
+
+#[test]
+fn test_path_payment_through_intermediate_hop() {
+    let s = setup();
+
+    // EUR → XLM at 5.0, XLM → token at 0.25: 100 EUR → 500 XLM → 125 tokens
+    s.router.set_rate(&s.eur.address, &s.xlm, &50000);
+    s.router.set_rate(&s.xlm, &s.token.address, &2500);
+    let payment_id = s.client.path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 100, 125, vec![&s.env, s.xlm.clone()]), &None);
+
+    assert_eq!(s.client.get_payment(&payment_id).amount, 125);
+    assert_eq!(s.token.balance(&s.vendor), 125 - 6);
+}
+
+#[test]
+fn test_path_payment_min_out_is_enforced() {
+    let s = setup();
+
+    // The router itself refuses to go below min_out
+    let result = s.client.try_path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 100, 121, vec![&s.env]), &None);
+    assert!(result.is_err());
+
+    // A router that reports 120 but delivers 110 is caught by the contract's own balance check
+    s.router.set_quirks(&10, &10000);
+    let result = s.client.try_path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 100, 115, vec![&s.env]), &None);
+    assert_eq!(result, Err(Ok(ContractError::SlippageExceeded)));
+
+    // Failed swaps are rolled back
+    assert_eq!(s.eur.balance(&s.payer), 1000);
+    assert_eq!(s.client.get_event(&s.event_id).total_volume, 0);
+
+    // Whatever the router delivers above min_out is paid on in full
+    let payment_id = s.client.path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 100, 110, vec![&s.env]), &None);
+    assert_eq!(s.client.get_payment(&payment_id).amount, 110);
+}
+
+#[test]
+fn test_unspent_input_returns_to_payer() {
+    let s = setup();
+
+    // The router only takes 80% of the input; the rest goes back to the payer
+    s.router.set_quirks(&0, &8000);
+    s.client.path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 100, 120, vec![&s.env]), &None);
+    assert_eq!(s.eur.balance(&s.payer), 1000 - 80);
+    assert_eq!(s.eur.balance(&s.client.address), 0);
+    assert_eq!(s.eur.allowance(&s.client.address, &s.router.address), 0);
+}
+
+#[test]
+fn test_path_payment_follows_event_rules() {
+    let s = setup();
+
+    // Sender mode: the fee is charged on top, in the event token
+    s.client.set_event_fee_mode(&s.event_id, &FeeMode::Sender);
+    let result = s.client.try_path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 100, 120, vec![&s.env]), &None);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientBalanceFromSender)));
+    StellarAssetClient::new(&s.env, &s.token.address).mint(&s.payer, &6);
+    s.client.path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 100, 120, vec![&s.env]), &None);
+    assert_eq!(s.token.balance(&s.vendor), 120);
+    assert_eq!(s.token.balance(&s.payer), 0);
+
+    // Paying in the event token itself skips the router
+    StellarAssetClient::new(&s.env, &s.token.address).mint(&s.payer, &105);
+    let direct = SwapRoute { send_token: s.token.address.clone(), amount_in: 100, min_out: 100, via: vec![&s.env] };
+    let payment_id = s.client.path_payment(&s.event_id, &s.payer, &s.vendor, &direct, &None);
+    assert_eq!(s.client.get_payment(&payment_id).amount, 100);
+
+    // Unregistered wallets and bad amounts are rejected before any swap
+    let stranger = Address::generate(&s.env);
+    let result = s.client.try_path_payment(&s.event_id, &stranger, &s.vendor, &route(&s, 100, 0, vec![&s.env]), &None);
+    assert_eq!(result, Err(Ok(ContractError::WalletNotRegistered)));
+    let result = s.client.try_path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 0, 0, vec![&s.env]), &None);
+    assert_eq!(result, Err(Ok(ContractError::AmountMustBePositive)));
+    let result = s.client.try_path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 100, -1, vec![&s.env]), &None);
+    assert_eq!(result, Err(Ok(ContractError::AmountMustBePositive)));
+    let result = s.client.try_path_payment(&s.event_id, &s.payer, &s.vendor, &route(&s, 5000, 0, vec![&s.env]), &None);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientBalanceFromSender)));
+}
+
+#[test]
+fn test_swap_router_is_admin_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = EventPaymentContractClient::new(&env, &env.register(EventPaymentContract, ()));
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let admin = Address::generate(&env);
+    let router = Address::generate(&env);
+
+    assert_eq!(client.try_set_swap_router(&admin, &router), Err(Ok(ContractError::ContractNotInitialized)));
+    client.initialize(&admin, &500, &token);
+    assert_eq!(client.get_swap_router(), None);
+    assert_eq!(client.try_set_swap_router(&router, &router), Err(Ok(ContractError::NotEventOrganizer)));
+    client.set_swap_router(&admin, &router);
+    assert_eq!(client.get_swap_router(), Some(router));
+
+    // Without a router only the event token can be sent
+    let s = setup();
+    let other = EventPaymentContractClient::new(&s.env, &s.env.register(EventPaymentContract, ()));
+    other.initialize(&s.admin, &500, &s.token.address);
+    let event_id = other.create_event(&s.admin, &String::from_str(&s.env, "No Router"), &None);
+    other.register_wallet_for_event(&event_id, &s.payer);
+    other.register_wallet_for_event(&event_id, &s.vendor);
+    let result = other.try_path_payment(&event_id, &s.payer, &s.vendor, &route(&s, 100, 0, vec![&s.env]), &None);
+    assert_eq!(result, Err(Ok(ContractError::RecordNotFound)));
+}
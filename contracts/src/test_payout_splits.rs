@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+// Event fee payouts split between co-organizers and partners
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, assert_last_event, MockTokenClient};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+#[test]
+fn test_payout_split_on_fee_withdrawal() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, organizer, sender, receiver) = setup_event_with_wallets(&env, &client);
+    let partner = Address::generate(&env);
+    let venue = Address::generate(&env);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    let share = |recipient: &Address, share_bps: u32| PayoutShare { recipient: recipient.clone(), share_bps };
+    assert_eq!(
+        client.try_set_payout_split(&event_id, &soroban_sdk::vec![&env, share(&organizer, 6000), share(&partner, 3000)]),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    client.set_payout_split(&event_id, &soroban_sdk::vec![
+        &env,
+        share(&organizer, 5000),
+        share(&partner, 3333),
+        share(&venue, 1667),
+    ]);
+
+    client.event_payment(&event_id, &sender, &receiver, &2010, &None);
+    client.set_event_status(&event_id, &false);
+
+    // Fees (100) are credited to each party instead of paid to the organizer
+    assert_eq!(client.withdraw_event_fees(&event_id), 100);
+    assert_eq!(client.get_event_fees(&event_id), 0);
+    assert_eq!(client.get_claimable_payout(&event_id, &partner), 33);
+    assert_eq!(client.get_claimable_payout(&event_id, &venue), 16);
+    // Rounding leftover goes to the organizer
+    assert_eq!(client.get_claimable_payout(&event_id, &organizer), 51);
+
+    // Each party pulls independently
+    assert_eq!(client.claim_payout(&event_id, &partner), 33);
+    assert_last_event(&env, &client.address, &PayoutClaimed { event_id, recipient: partner.clone(), amount: 33 });
+    assert_eq!(token.balance(&partner), 33);
+    assert_eq!(client.claim_payout(&event_id, &partner), 0);
+
+    assert_eq!(client.claim_payout(&event_id, &organizer), 51);
+    assert_eq!(token.balance(&organizer), 51);
+    assert_eq!(client.get_claimable_payout(&event_id, &venue), 16);
+}
+
+#[test]
+fn test_payout_split_leftover_goes_to_the_organizer() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let organizer = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let venue = Address::generate(&env);
+    let share = |recipient: &Address, share_bps: u32| PayoutShare { recipient: recipient.clone(), share_bps };
+
+    // Same split in both orders, with the organizer outside of it
+    for (name, shares) in [
+        ("Partner First", soroban_sdk::vec![&env, share(&partner, 3333), share(&venue, 6667)]),
+        ("Venue First", soroban_sdk::vec![&env, share(&venue, 6667), share(&partner, 3333)]),
+    ] {
+        let event_id = client.create_event(&organizer, &String::from_str(&env, name), &None);
+        client.register_wallet_for_event(&event_id, &sender);
+        client.register_wallet_for_event(&event_id, &receiver);
+        client.set_payout_split(&event_id, &shares);
+        client.event_payment(&event_id, &sender, &receiver, &2010, &None);
+        client.set_event_status(&event_id, &false);
+
+        assert_eq!(client.withdraw_event_fees(&event_id), 100);
+        assert_eq!(client.get_claimable_payout(&event_id, &partner), 33);
+        assert_eq!(client.get_claimable_payout(&event_id, &venue), 66);
+        assert_eq!(client.get_claimable_payout(&event_id, &organizer), 1);
+    }
+
+    // Fees too large to split are an error, not a panic
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Huge Split"), &None);
+    client.set_payout_split(&event_id, &soroban_sdk::vec![&env, share(&partner, 5000), share(&venue, 5000)]);
+    client.set_event_status(&event_id, &false);
+    env.as_contract(&client.address, || EventPaymentContract::add_event_fees(&env, event_id, i128::MAX / 2));
+    assert_eq!(client.try_withdraw_event_fees(&event_id), Err(Ok(ContractError::ArithmeticOverflow)));
+}
@@ -0,0 +1,191 @@
+#![cfg(test)]
+
+// Session grants: delegate keys spending on behalf of a wallet within an event
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, MockTokenClient};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token::{StellarAssetClient, TokenClient}, Address, Env, String};
+
+#[test]
+fn test_delegated_event_payment() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, grantor, receiver) = setup_event_with_wallets(&env, &client);
+    let delegate = Address::generate(&env);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    client.create_session_grant(&grantor, &delegate, &event_id, &500, &3600);
+    assert_eq!(token.allowance(&grantor, &client.address), 500);
+
+    // Only the delegate signs the payment
+    client.delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &200, &None);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, delegate);
+
+    // Grantor is debited, receiver gets net amount and the fee is accumulated
+    assert_eq!(token.balance(&grantor), 1000000 - 200);
+    assert_eq!(token.balance(&receiver), 190);
+    assert_eq!(client.get_event_fees(&event_id), 10);
+
+    let grant = client.get_session_grant(&grantor, &delegate, &event_id);
+    assert_eq!(grant.spent, 200);
+    assert_eq!(token.allowance(&grantor, &client.address), 300);
+
+    // Cap is enforced
+    let result = client.try_delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &301, &None);
+    assert_eq!(result, Err(Ok(ContractError::SessionGrantExceeded)));
+    client.delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &300, &None);
+}
+
+#[test]
+fn test_session_grant_expiry_scope_and_revocation() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, grantor, receiver) = setup_event_with_wallets(&env, &client);
+    let delegate = Address::generate(&env);
+
+    // Expiry must be in the future
+    let result = client.try_create_session_grant(&grantor, &delegate, &event_id, &500, &0);
+    assert_eq!(result, Err(Ok(ContractError::Expired)));
+
+    // Grantor must be registered in the event
+    let outsider = Address::generate(&env);
+    let result = client.try_create_session_grant(&outsider, &delegate, &event_id, &500, &3600);
+    assert_eq!(result, Err(Ok(ContractError::WalletNotRegistered)));
+
+    client.create_session_grant(&grantor, &delegate, &event_id, &500, &3600);
+
+    // Grant is scoped to its event
+    let other_organizer = Address::generate(&env);
+    let other_event = client.create_event(&other_organizer, &String::from_str(&env, "Other"), &None);
+    client.register_wallet_for_event(&other_event, &grantor);
+    client.register_wallet_for_event(&other_event, &receiver);
+    let result = client.try_delegated_event_payment(&other_event, &grantor, &delegate, &receiver, &100, &None);
+    assert_eq!(result, Err(Ok(ContractError::RecordNotFound)));
+
+    // Unknown delegate has no grant
+    let stranger = Address::generate(&env);
+    let result = client.try_delegated_event_payment(&event_id, &grantor, &stranger, &receiver, &100, &None);
+    assert_eq!(result, Err(Ok(ContractError::RecordNotFound)));
+
+    // Expired grant is rejected
+    env.ledger().with_mut(|l| l.timestamp = 3600);
+    let result = client.try_delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &100, &None);
+    assert_eq!(result, Err(Ok(ContractError::Expired)));
+
+    // Revoked grant disappears
+    client.revoke_session_grant(&grantor, &delegate, &event_id);
+    let result = client.try_get_session_grant(&grantor, &delegate, &event_id);
+    assert_eq!(result, Err(Ok(ContractError::RecordNotFound)));
+    let result = client.try_revoke_session_grant(&grantor, &delegate, &event_id);
+    assert_eq!(result, Err(Ok(ContractError::RecordNotFound)));
+}
+
+#[test]
+fn test_session_grant_allowance_follows_unspent_caps() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, grantor, receiver) = setup_event_with_wallets(&env, &client);
+    let (phone, watch) = (Address::generate(&env), Address::generate(&env));
+    let token = MockTokenClient::new(&env, &token_address);
+
+    // Re-creating a grant replaces its allowance instead of stacking it
+    client.create_session_grant(&grantor, &phone, &event_id, &500, &3600);
+    client.create_session_grant(&grantor, &phone, &event_id, &300, &3600);
+    client.create_session_grant(&grantor, &watch, &event_id, &200, &3600);
+    assert_eq!(token.allowance(&grantor, &client.address), 300 + 200);
+
+    // In Sender mode the fee on top counts against the cap too
+    client.set_event_fee_mode(&event_id, &FeeMode::Sender);
+    client.delegated_event_payment(&event_id, &grantor, &phone, &receiver, &200, &None);
+    assert_eq!(client.get_session_grant(&grantor, &phone, &event_id).spent, 210);
+    let result = client.try_delegated_event_payment(&event_id, &grantor, &phone, &receiver, &90, &None);
+    assert_eq!(result, Err(Ok(ContractError::SessionGrantExceeded)));
+    client.delegated_event_payment(&event_id, &grantor, &phone, &receiver, &85, &None);
+    assert_eq!(client.get_session_grant(&grantor, &phone, &event_id).spent, 210 + 89);
+
+    // Revoking withdraws exactly the unspent part of each grant
+    client.revoke_session_grant(&grantor, &phone, &event_id);
+    assert_eq!(token.allowance(&grantor, &client.address), 200);
+    client.revoke_session_grant(&grantor, &watch, &event_id);
+    assert_eq!(token.allowance(&grantor, &client.address), 0);
+}
+
+#[test]
+fn test_session_grant_allowance_expiry_is_relative_to_the_ledger() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.sequence_number = 5_000_000);
+
+    let client = EventPaymentContractClient::new(&env, &env.register(EventPaymentContract, ()));
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    client.initialize(&Address::generate(&env), &500, &token);
+    let (event_id, _organizer, grantor, receiver) = setup_event_with_wallets(&env, &client);
+    StellarAssetClient::new(&env, &token).mint(&grantor, &1000);
+    let token = TokenClient::new(&env, &token);
+
+    // Far past ledger 3_110_400, the approval still lands in the future
+    let delegate = Address::generate(&env);
+    client.create_session_grant(&grantor, &delegate, &event_id, &500, &3600);
+    assert_eq!(token.allowance(&grantor, &client.address), 500);
+    client.delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &100, &None);
+    assert_eq!(token.balance(&receiver), 95);
+
+    env.ledger().with_mut(|l| l.sequence_number += 518_401);
+    assert_eq!(token.allowance(&grantor, &client.address), 0);
+}
+
+#[test]
+fn test_session_grant_cap_is_independent_of_the_allowance() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, grantor, receiver) = setup_event_with_wallets(&env, &client);
+    let delegate = Address::generate(&env);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    client.create_session_grant(&grantor, &delegate, &event_id, &500, &3600);
+    client.set_event_fee_mode(&event_id, &FeeMode::Sender);
+
+    // A larger allowance granted for something else doesn't widen the grant
+    client.authorize_fee_payments(&grantor, &10_000);
+    client.delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &400, &None);
+    assert_eq!(client.get_session_grant(&grantor, &delegate, &event_id).spent, 420);
+    let result = client.try_delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &78, &None);
+    assert_eq!(result, Err(Ok(ContractError::SessionGrantExceeded)));
+
+    // Allowance spent elsewhere doesn't count against the grant either
+    assert_eq!(token.allowance(&grantor, &client.address), 500 + 10_000 - 420);
+    client.delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &76, &None);
+    assert_eq!(client.get_session_grant(&grantor, &delegate, &event_id).spent, 420 + 79);
+}
+
+#[test]
+fn test_session_grant_cannot_outlive_its_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| {
+        l.sequence_number = 5_000_000;
+        l.max_entry_ttl = 6_312_000;
+    });
+
+    let client = EventPaymentContractClient::new(&env, &env.register(EventPaymentContract, ()));
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    client.initialize(&Address::generate(&env), &500, &token);
+    let (event_id, _organizer, grantor, receiver) = setup_event_with_wallets(&env, &client);
+    StellarAssetClient::new(&env, &token).mint(&grantor, &1000);
+    let delegate = Address::generate(&env);
+
+    // The allowance lasts 518_400 ledgers of 5s: 30 days
+    let thirty_days = 30 * 86400;
+    let result = client.try_create_session_grant(&grantor, &delegate, &event_id, &500, &(thirty_days + 1));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+    client.create_session_grant(&grantor, &delegate, &event_id, &500, &thirty_days);
+
+    // On the grant's last day the allowance is still live
+    env.ledger().with_mut(|l| {
+        l.timestamp = thirty_days - 60;
+        l.sequence_number += 518_400 - 12;
+    });
+    client.delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &100, &None);
+    assert_eq!(TokenClient::new(&env, &token).balance(&receiver), 95);
+
+    env.ledger().with_mut(|l| l.timestamp = thirty_days);
+    let result = client.try_delegated_event_payment(&event_id, &grantor, &delegate, &receiver, &100, &None);
+    assert_eq!(result, Err(Ok(ContractError::Expired)));
+}
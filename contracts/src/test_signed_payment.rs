@@ -0,0 +1,287 @@
+#![cfg(test)]
+
+extern crate std;
+
+// Payment intents signed off-chain with ed25519 and secp256r1 keys or passkeys and
+// submitted later by the vendor, with nonce replay protection
+use crate::{
+    ContractError, EventPaymentContract, EventPaymentContractClient, PaymentIntent, PaymentKey, SignedPaymentSubmitted,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use ed25519_dalek::Signer as _;
+use p256::ecdsa::{Signature, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Bytes, BytesN, Env, String,
+};
+
+struct Setup<'a> {
+    env: Env,
+    client: EventPaymentContractClient<'a>,
+    token: TokenClient<'a>,
+    event_id: u64,
+    payer: Address,
+    vendor: Address,
+}
+
+/// Payer holds 1000 tokens and allows the contract to draw 500
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let client = EventPaymentContractClient::new(&env, &env.register(EventPaymentContract, ()));
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    client.initialize(&Address::generate(&env), &500, &token);
+
+    let organizer = Address::generate(&env);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Offline Fest"), &None);
+    let (payer, vendor) = (Address::generate(&env), Address::generate(&env));
+    client.register_wallet_for_event(&event_id, &payer);
+    client.register_wallet_for_event(&event_id, &vendor);
+    StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    let token = TokenClient::new(&env, &token);
+    token.approve(&payer, &client.address, &500, &1000);
+
+    Setup { env, client, token, event_id, payer, vendor }
+}
+
+fn intent(s: &Setup, amount: i128, nonce: u64) -> PaymentIntent {
+    PaymentIntent {
+        event_id: s.event_id,
+        payer: s.payer.clone(),
+        payee: s.vendor.clone(),
+        amount,
+        nonce,
+        expires_at: 4600,
+    }
+}
+
+fn payload(client: &EventPaymentContractClient, intent: &PaymentIntent) -> std::vec::Vec<u8> {
+    let payload = client.signed_payment_payload(intent);
+    let mut bytes = std::vec![0; payload.len() as usize];
+    payload.copy_into_slice(&mut bytes);
+    bytes
+}
+
+fn ed25519_key(s: &Setup) -> ed25519_dalek::SigningKey {
+    let key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+    let public_key = BytesN::from_array(&s.env, &key.verifying_key().to_bytes());
+    s.client.set_payment_key(&s.payer, &Some(PaymentKey::Ed25519(public_key)));
+    key
+}
+
+fn ed25519_sign(s: &Setup, key: &ed25519_dalek::SigningKey, intent: &PaymentIntent) -> BytesN<64> {
+    BytesN::from_array(&s.env, &key.sign(&payload(&s.client, intent)).to_bytes())
+}
+
+#[test]
+fn test_vendor_submits_ed25519_signed_payment() {
+    let s = setup();
+    let key = ed25519_key(&s);
+    let intent = intent(&s, 200, 42);
+    let signature = ed25519_sign(&s, &key, &intent);
+
+    // The vendor submits later without any authorization from the payer
+    s.env.set_auths(&[]);
+    let payment_id = s.client.submit_signed_payment(&intent, &signature);
+
+    let (address, topics, data) = s.env.events().all().last().unwrap();
+    let expected = SignedPaymentSubmitted { event_id: s.event_id, payer: s.payer.clone(), nonce: 42, payment_id };
+    assert_eq!(address, s.client.address);
+    assert_eq!(topics, soroban_sdk::Event::topics(&expected, &s.env));
+    assert_eq!(soroban_sdk::vec![&s.env, data], soroban_sdk::vec![&s.env, soroban_sdk::Event::data(&expected, &s.env)]);
+
+    let payment = s.client.get_payment(&payment_id);
+    assert_eq!((payment.from, payment.to, payment.amount, payment.fee_amount), (s.payer.clone(), s.vendor.clone(), 200, 10));
+    assert_eq!(s.token.balance(&s.payer), 800);
+    assert_eq!(s.token.balance(&s.vendor), 190);
+    assert_eq!(s.token.allowance(&s.payer, &s.client.address), 300);
+    assert!(s.client.is_nonce_used(&s.payer, &42));
+
+    // The same intent can't be replayed
+    let result = s.client.try_submit_signed_payment(&intent, &signature);
+    assert_eq!(result, Err(Ok(ContractError::NonceAlreadyUsed)));
+    assert_eq!(s.token.balance(&s.payer), 800);
+}
+
+fn secp256r1_key(s: &Setup) -> SigningKey {
+    let key = SigningKey::from_bytes(&[9; 32].into()).unwrap();
+    let point = key.verifying_key().to_encoded_point(false);
+    let public_key = BytesN::from_array(&s.env, point.as_bytes().try_into().unwrap());
+    s.client.set_payment_key(&s.payer, &Some(PaymentKey::Secp256r1(public_key.clone())));
+    assert_eq!(s.client.get_payment_key(&s.payer), Some(PaymentKey::Secp256r1(public_key)));
+    key
+}
+
+// ECDSA signs the SHA-256 of the message; the host only accepts low-S signatures
+fn secp256r1_sign(s: &Setup, key: &SigningKey, message: &[u8]) -> BytesN<64> {
+    let signature: Signature = key.sign(message);
+    let signature = signature.normalize_s().unwrap_or(signature);
+    BytesN::from_array(&s.env, &signature.to_bytes().into())
+}
+
+fn sha256(s: &Setup, bytes: &[u8]) -> [u8; 32] {
+    s.env.crypto().sha256(&Bytes::from_slice(&s.env, bytes)).to_array()
+}
+
+/// What a browser returns for `navigator.credentials.get` with the intent's challenge
+struct Assertion {
+    authenticator_data: std::vec::Vec<u8>,
+    client_data_json: std::string::String,
+}
+
+impl Assertion {
+    fn new(s: &Setup, intent: &PaymentIntent, kind: &str, flags: u8) -> Self {
+        let mut authenticator_data = std::vec![0x5a; 32];
+        authenticator_data.extend_from_slice(&[flags, 0, 0, 0, 7]);
+        let challenge = URL_SAFE_NO_PAD.encode(sha256(s, &payload(&s.client, intent)));
+        let client_data_json = std::format!(
+            r#"{{"type":"{kind}","challenge":"{challenge}","origin":"https://pay.example","crossOrigin":false}}"#
+        );
+        Assertion { authenticator_data, client_data_json }
+    }
+
+    fn submit(&self, s: &Setup, key: &SigningKey, intent: &PaymentIntent) -> Result<u64, ContractError> {
+        let mut message = self.authenticator_data.clone();
+        message.extend_from_slice(&sha256(s, self.client_data_json.as_bytes()));
+        let signature = secp256r1_sign(s, key, &message);
+        match s.client.try_submit_passkey_payment(
+            intent,
+            &Bytes::from_slice(&s.env, &self.authenticator_data),
+            &Bytes::from_slice(&s.env, self.client_data_json.as_bytes()),
+            &signature,
+        ) {
+            Ok(payment_id) => Ok(payment_id.unwrap()),
+            Err(error) => Err(error.unwrap()),
+        }
+    }
+}
+
+#[test]
+fn test_secp256r1_signature() {
+    let s = setup();
+    let key = secp256r1_key(&s);
+
+    let intent = intent(&s, 300, 1);
+    let signature = secp256r1_sign(&s, &key, &payload(&s.client, &intent));
+
+    s.client.submit_signed_payment(&intent, &signature);
+    assert_eq!(s.token.balance(&s.vendor), 300 - 15);
+
+    // Compressed points can't be registered
+    let mut compressed = [0u8; 65];
+    compressed[..33].copy_from_slice(key.verifying_key().to_encoded_point(true).as_bytes());
+    let result = s.client.try_set_payment_key(&s.payer, &Some(PaymentKey::Secp256r1(BytesN::from_array(&s.env, &compressed))));
+    assert_eq!(result, Err(Ok(ContractError::InvalidSignature)));
+}
+
+#[test]
+fn test_passkey_assertion() {
+    let s = setup();
+    let key = secp256r1_key(&s);
+    let signed = intent(&s, 200, 3);
+
+    // The assertion must be a user-present `webauthn.get` carrying this intent's challenge
+    let created = Assertion::new(&s, &signed, "webauthn.create", 0x05);
+    assert_eq!(created.submit(&s, &key, &signed), Err(ContractError::InvalidSignature));
+    let absent = Assertion::new(&s, &signed, "webauthn.get", 0x04);
+    assert_eq!(absent.submit(&s, &key, &signed), Err(ContractError::InvalidSignature));
+    let other = Assertion::new(&s, &PaymentIntent { amount: 400, ..signed.clone() }, "webauthn.get", 0x05);
+    assert_eq!(other.submit(&s, &key, &signed), Err(ContractError::InvalidSignature));
+    assert!(!s.client.is_nonce_used(&s.payer, &3));
+
+    let assertion = Assertion::new(&s, &signed, "webauthn.get", 0x05);
+    s.env.set_auths(&[]);
+    let payment_id = assertion.submit(&s, &key, &signed).unwrap();
+    assert_eq!(s.client.get_payment(&payment_id).amount, 200);
+    assert_eq!(s.token.balance(&s.vendor), 190);
+    assert_eq!(assertion.submit(&s, &key, &signed), Err(ContractError::NonceAlreadyUsed));
+
+    // A signature from another key fails in the host, and ed25519 keys can't use assertions
+    let next = intent(&s, 100, 4);
+    let stranger = SigningKey::from_bytes(&[3; 32].into()).unwrap();
+    let assertion = Assertion::new(&s, &next, "webauthn.get", 0x05);
+    assert!(s.client.try_submit_passkey_payment(
+        &next,
+        &Bytes::from_slice(&s.env, &assertion.authenticator_data),
+        &Bytes::from_slice(&s.env, assertion.client_data_json.as_bytes()),
+        &secp256r1_sign(&s, &stranger, b"not the assertion"),
+    ).is_err());
+    s.env.mock_all_auths();
+    ed25519_key(&s);
+    assert_eq!(assertion.submit(&s, &key, &next), Err(ContractError::InvalidSignature));
+}
+
+#[test]
+fn test_tampered_or_foreign_intents_are_rejected() {
+    let s = setup();
+    let key = ed25519_key(&s);
+    let signed = intent(&s, 200, 5);
+    let signature = ed25519_sign(&s, &key, &signed);
+
+    // A changed amount or payee no longer matches the signature
+    let result = s.client.try_submit_signed_payment(&PaymentIntent { amount: 400, ..signed.clone() }, &signature);
+    assert!(result.is_err());
+    let result = s.client.try_submit_signed_payment(&PaymentIntent { payee: Address::generate(&s.env), ..signed.clone() }, &signature);
+    assert!(result.is_err());
+
+    // The payload is bound to this contract: another deployment rejects the signature
+    let other = EventPaymentContractClient::new(&s.env, &s.env.register(EventPaymentContract, ()));
+    other.initialize(&Address::generate(&s.env), &500, &s.token.address);
+    other.set_payment_key(&s.payer, &s.client.get_payment_key(&s.payer));
+    assert!(other.try_submit_signed_payment(&signed, &signature).is_err());
+
+    // Failed submissions don't burn the nonce
+    assert!(!s.client.is_nonce_used(&s.payer, &5));
+    s.client.submit_signed_payment(&signed, &signature);
+    assert_eq!(s.token.balance(&s.vendor), 190);
+}
+
+#[test]
+fn test_expiry_cancellation_and_missing_key() {
+    let s = setup();
+
+    // No registered key: nothing to verify against
+    let unsigned = intent(&s, 100, 1);
+    let result = s.client.try_submit_signed_payment(&unsigned, &BytesN::from_array(&s.env, &[0; 64]));
+    assert_eq!(result, Err(Ok(ContractError::InvalidSignature)));
+
+    let key = ed25519_key(&s);
+    let first = intent(&s, 100, 1);
+    let second = intent(&s, 100, 2);
+    let (first_sig, second_sig) = (ed25519_sign(&s, &key, &first), ed25519_sign(&s, &key, &second));
+
+    // The payer cancels a lost intent before the vendor submits it
+    s.client.cancel_payment_nonce(&s.payer, &1);
+    assert_eq!(s.client.try_submit_signed_payment(&first, &first_sig), Err(Ok(ContractError::NonceAlreadyUsed)));
+    assert_eq!(s.client.try_cancel_payment_nonce(&s.payer, &1), Err(Ok(ContractError::NonceAlreadyUsed)));
+
+    // Intents can't be submitted from their expiry on
+    s.env.ledger().with_mut(|l| l.timestamp = 4600);
+    assert_eq!(s.client.try_submit_signed_payment(&second, &second_sig), Err(Ok(ContractError::Expired)));
+
+    // Removing the key disables signed payments
+    s.env.ledger().with_mut(|l| l.timestamp = 1000);
+    s.client.set_payment_key(&s.payer, &None);
+    assert_eq!(s.client.try_submit_signed_payment(&second, &second_sig), Err(Ok(ContractError::InvalidSignature)));
+}
+
+#[test]
+fn test_signed_payment_follows_event_rules() {
+    let s = setup();
+    let key = ed25519_key(&s);
+
+    // The amount is drawn from the allowance: 600 is above what the payer allowed
+    let too_much = intent(&s, 600, 1);
+    let result = s.client.try_submit_signed_payment(&too_much, &ed25519_sign(&s, &key, &too_much));
+    assert_eq!(result, Err(Ok(ContractError::InsufficientAllowance)));
+
+    // Closed events don't accept signed payments either
+    s.client.set_event_status(&s.event_id, &false);
+    let closed = intent(&s, 100, 2);
+    let result = s.client.try_submit_signed_payment(&closed, &ed25519_sign(&s, &key, &closed));
+    assert_eq!(result, Err(Ok(ContractError::EventNotActive)));
+    assert!(!s.client.is_nonce_used(&s.payer, &2));
+}
@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+use crate::simulation::{self, PaymentDistribution, Scenario, SimulationError};
+
+const FESTIVAL: &str = include_str!("../scenarios/festival.json");
+
+#[test]
+fn test_simulation_fixed_payments() {
+    let scenario = Scenario::from_json(
+        r#"{
+            "name": "Fixed",
+            "events": [{
+                "name": "Bar",
+                "attendees": 2,
+                "vendors": 1,
+                "initial_balance": 1000,
+                "payments": 10,
+                "distribution": { "kind": "fixed", "amount": 200 }
+            }]
+        }"#,
+    )
+    .unwrap();
+
+    let report = simulation::run(&scenario).unwrap();
+    let event = &report.events[0];
+
+    // Attendees may run out of balance, but every successful payment
+    // pays 10 in fees (5% of 200)
+    assert_eq!(event.fee_rate, 500);
+    assert_eq!(event.payments_succeeded + event.payments_failed, 10);
+    assert_eq!(event.gross_volume, 200 * event.payments_succeeded as i128);
+    assert_eq!(event.event_fees, 10 * event.payments_succeeded as i128);
+    assert_eq!(event.vendor_net, 190 * event.payments_succeeded as i128);
+    assert_eq!(event.organizer_revenue, event.event_fees);
+
+    // Fees were withdrawn, so nothing is left in the contract
+    assert_eq!(report.balances.contract, 0);
+    assert_eq!(report.balances.vendors, event.vendor_net);
+    assert_eq!(report.balances.organizers, event.organizer_revenue);
+    assert_eq!(report.balances.attendees, 2000 - event.gross_volume);
+}
+
+#[test]
+fn test_simulation_reports_resources_per_call() {
+    let scenario = Scenario::from_json(FESTIVAL).unwrap();
+    let report = simulation::run(&scenario).unwrap();
+
+    let payments = report.calls.iter().find(|c| c.function == "event_payment").unwrap();
+    assert_eq!(payments.calls, 300 + 150);
+    assert!(payments.max_instructions > 0);
+    assert!(payments.max_write_entries > 0);
+    assert!(payments.total_fee > 0);
+
+    let registrations = report.calls.iter().find(|c| c.function == "register_wallet_for_event").unwrap();
+    assert_eq!(registrations.calls, 40 + 6 + 25 + 4);
+
+    // Every token minted is accounted for in the final balances
+    let minted = 40 * 5000 + 25 * 2000;
+    let b = &report.balances;
+    assert_eq!(b.attendees + b.vendors + b.organizers + b.contract, minted);
+}
+
+#[test]
+fn test_simulation_is_deterministic() {
+    let scenario = Scenario::from_json(FESTIVAL).unwrap();
+    let first = simulation::run(&scenario).unwrap();
+    let second = simulation::run(&scenario).unwrap();
+
+    for (a, b) in first.events.iter().zip(second.events.iter()) {
+        assert_eq!(a.gross_volume, b.gross_volume);
+        assert_eq!(a.event_fees, b.event_fees);
+    }
+}
+
+#[test]
+fn test_simulation_without_settlement_keeps_fees_in_contract() {
+    let mut scenario = Scenario::from_json(FESTIVAL).unwrap();
+    scenario.events.truncate(1);
+    scenario.events[0].settle = false;
+    scenario.events[0].distribution = PaymentDistribution::Fixed { amount: 100 };
+
+    let report = simulation::run(&scenario).unwrap();
+    let event = &report.events[0];
+    assert_eq!(event.organizer_revenue, 0);
+    assert_eq!(report.balances.contract, event.event_fees);
+}
+
+#[test]
+fn test_simulation_rejects_invalid_scenarios() {
+    let result = Scenario::from_json(r#"{ "name": "Empty", "events": [] }"#);
+    assert!(matches!(result, Err(SimulationError::InvalidScenario(_))));
+
+    let mut scenario = Scenario::from_json(FESTIVAL).unwrap();
+    scenario.events[0].distribution = PaymentDistribution::Uniform { min: 50, max: 10 };
+    assert!(matches!(simulation::run(&scenario), Err(SimulationError::InvalidScenario(_))));
+
+    // Contract errors during setup are surfaced with the failing call
+    let mut scenario = Scenario::from_json(FESTIVAL).unwrap();
+    scenario.events[1].name = scenario.events[0].name.clone();
+    assert!(matches!(
+        simulation::run(&scenario),
+        Err(SimulationError::Contract { call: "create_event", .. })
+    ));
+}
@@ -0,0 +1,224 @@
+#![cfg(test)]
+
+// Property tests: after any sequence of operations the contract holds exactly
+// what it owes (no stray tokens are sent to it in these runs)
+extern crate std;
+
+use std::vec::Vec;
+
+use crate::simulation::Rng;
+use crate::{ContractError, EventPaymentContract, EventPaymentContractClient, LoyaltyConfig, LoyaltyReward, PayoutShare, TokensSwept};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, Event as _, String,
+};
+
+struct World<'a> {
+    env: Env,
+    client: EventPaymentContractClient<'a>,
+    token: TokenClient<'a>,
+    admin: Address,
+    events: Vec<u64>,
+    organizers: Vec<Address>,
+    wallets: Vec<Address>,
+    partner: Address,
+}
+
+fn setup_world<'a>() -> World<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EventPaymentContract, ());
+    let client = EventPaymentContractClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(issuer).address();
+    let token = TokenClient::new(&env, &token_address);
+    let token_admin = StellarAssetClient::new(&env, &token_address);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &500, &token_address);
+    client.set_active_withdrawal_limit(&admin, &5000);
+
+    let partner = Address::generate(&env);
+    let mut events = Vec::new();
+    let mut organizers = Vec::new();
+    let wallets: Vec<Address> = (0..4).map(|_| Address::generate(&env)).collect();
+
+    for (i, name) in ["Alpha", "Beta"].iter().enumerate() {
+        let organizer = Address::generate(&env);
+        let event_id = client.create_event(&organizer, &String::from_str(&env, name), &None);
+        for wallet in &wallets {
+            client.register_wallet_for_event(&event_id, wallet);
+        }
+        client.set_loyalty_config(&event_id, &LoyaltyConfig { earn_rate_bps: 1000, point_value_bps: 1000 });
+        if i == 1 {
+            client.set_payout_split(&event_id, &vec![
+                &env,
+                PayoutShare { recipient: organizer.clone(), share_bps: 7000 },
+                PayoutShare { recipient: partner.clone(), share_bps: 3000 },
+            ]);
+        }
+        events.push(event_id);
+        organizers.push(organizer);
+    }
+
+    for wallet in &wallets {
+        token_admin.mint(wallet, &100_000);
+    }
+
+    World { env, client, token, admin, events, organizers, wallets, partner }
+}
+
+impl World<'_> {
+    // Run one random operation; failures are fine, they just roll back
+    fn step(&self, rng: &mut Rng) {
+        let event = self.events[rng.below(self.events.len() as u64) as usize];
+        let event_index = self.events.iter().position(|e| *e == event).unwrap();
+        let from = &self.wallets[rng.below(self.wallets.len() as u64) as usize];
+        let to = &self.wallets[rng.below(self.wallets.len() as u64) as usize];
+        let amount = 1 + rng.below(3000) as i128;
+        let c = &self.client;
+
+        match rng.below(12) {
+            0 | 1 => {
+                let _ = c.try_event_payment(&event, from, to, &amount, &None);
+            }
+            2 => {
+                let _ = c.try_escrow_payment(&event, from, to, &amount, &3600, &None);
+            }
+            3 => {
+                let _ = c.try_confirm_escrow(&(1 + rng.below(10)));
+            }
+            4 => {
+                let escrow_id = 1 + rng.below(10);
+                let _ = c.try_dispute_escrow(&escrow_id);
+                let _ = c.try_resolve_escrow_dispute(&escrow_id, &(rng.below(2) == 0));
+            }
+            5 => {
+                let _ = c.try_open_bill(&event, to, &amount, &None, &(self.env.ledger().timestamp() + 3600));
+            }
+            6 => {
+                let _ = c.try_contribute_to_bill(&(1 + rng.below(5)), from, &(amount / 2));
+            }
+            7 => {
+                let bill_id = 1 + rng.below(5);
+                let _ = c.try_reclaim_bill_share(&bill_id, from);
+                let _ = c.try_cancel_bill(&bill_id);
+            }
+            8 => {
+                let _ = c.try_redeem_points(&event, from, &(amount / 10), &LoyaltyReward::Voucher);
+            }
+            9 => {
+                let _ = c.try_withdraw_all_fees(&self.organizers[event_index]);
+            }
+            10 => {
+                let _ = c.try_claim_payout(&event, &self.partner);
+                let _ = c.try_claim_payout(&event, &self.organizers[event_index]);
+            }
+            _ => {
+                // Let escrow dispute windows and bill deadlines pass
+                self.env.ledger().with_mut(|l| l.timestamp += 1800);
+            }
+        }
+    }
+
+    fn assert_solvent(&self, seed: u64, step: usize) {
+        let solvency = self.client.check_solvency();
+        assert_eq!(
+            solvency.liabilities,
+            self.token.balance(&self.client.address),
+            "seed {seed}, step {step}: liabilities don't match the contract balance"
+        );
+        assert!(solvency.solvent);
+        assert_eq!(solvency.surplus, 0);
+    }
+}
+
+#[test]
+fn test_liabilities_match_balance_after_random_operations() {
+    for seed in 0..6 {
+        let world = setup_world();
+        let mut rng = Rng(seed);
+        let mut max_liabilities = 0;
+
+        for step in 0..60 {
+            world.step(&mut rng);
+            world.assert_solvent(seed, step);
+            max_liabilities = max_liabilities.max(world.client.get_liabilities());
+        }
+        // The sequence actually moved funds through the contract
+        assert!(max_liabilities > 0);
+
+        // Winding everything down leaves nothing owed and nothing held
+        for (event, organizer) in world.events.iter().zip(world.organizers.iter()) {
+            world.env.ledger().with_mut(|l| l.timestamp += 7200);
+            for escrow_id in 1..=10 {
+                let _ = world.client.try_release_escrow(&escrow_id);
+                let _ = world.client.try_resolve_escrow_dispute(&escrow_id, &true);
+            }
+            for bill_id in 1..=5 {
+                let _ = world.client.try_cancel_bill(&bill_id);
+            }
+            world.client.set_event_status(event, &false);
+            world.client.withdraw_event_fees(event);
+            world.client.claim_payout(event, organizer);
+            world.client.claim_payout(event, &world.partner);
+        }
+        world.assert_solvent(seed, 60);
+        assert_eq!(world.client.get_liabilities(), 0);
+    }
+}
+
+#[test]
+fn test_stray_tokens_show_up_as_surplus() {
+    let world = setup_world();
+    let (event, from, to) = (world.events[0], &world.wallets[0], &world.wallets[1]);
+
+    world.client.event_payment(&event, from, to, &1000, &None);
+    world.client.escrow_payment(&event, from, to, &300, &3600, &None);
+    assert_eq!(world.client.get_liabilities(), 50 + 300);
+
+    // Tokens sent directly to the contract aren't owed to anyone
+    world.token.transfer(from, &world.client.address, &25);
+    let solvency = world.client.check_solvency();
+    assert_eq!(solvency.balance, 375);
+    assert_eq!(solvency.surplus, 25);
+    assert!(solvency.solvent);
+}
+
+#[test]
+fn test_sweep_only_moves_surplus() {
+    let world = setup_world();
+    let (event, from, to) = (world.events[0], &world.wallets[0], &world.wallets[1]);
+    let rescuer = Address::generate(&world.env);
+
+    world.client.event_payment(&event, from, to, &1000, &None);
+    world.token.transfer(from, &world.client.address, &25);
+
+    // Fees owed to the organizer can't be swept
+    assert_eq!(
+        world.client.try_sweep(&world.token.address, &rescuer, &26),
+        Err(Ok(ContractError::SweepExceedsSurplus))
+    );
+    world.client.sweep(&world.token.address, &rescuer, &25);
+    let audit = TokensSwept {
+        admin: world.admin.clone(),
+        token: world.token.address.clone(),
+        to: rescuer.clone(),
+        amount: 25,
+    };
+    let (_, topics, data) = world.env.events().all().last().unwrap();
+    assert_eq!(topics, audit.topics(&world.env));
+    assert_eq!(vec![&world.env, data], vec![&world.env, audit.data(&world.env)]);
+    assert_eq!(world.token.balance(&rescuer), 25);
+    assert_eq!(world.client.check_solvency().surplus, 0);
+    assert_eq!(world.client.get_event_fees(&event), 50);
+
+    // Other tokens aren't owed to anyone and can be swept entirely
+    let other_address = world.env.register_stellar_asset_contract_v2(Address::generate(&world.env)).address();
+    StellarAssetClient::new(&world.env, &other_address).mint(&world.client.address, &400);
+    world.client.sweep(&other_address, &rescuer, &400);
+    assert_eq!(TokenClient::new(&world.env, &other_address).balance(&rescuer), 400);
+}
@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+// Per-wallet spending caps within an event: per payment, per day and per event
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets};
+use soroban_sdk::testutils::Ledger;
+
+#[test]
+fn test_spending_limit_per_payment_and_event() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, sender, receiver) = setup_event_with_wallets(&env, &client);
+
+    // Organizer sets an event-wide cap
+    client.set_event_spending_limit(&event_id, &SpendingLimit {
+        per_payment: Some(100),
+        per_day: None,
+        per_event: Some(250),
+    });
+
+    // Payment above per-payment cap fails
+    let result = client.try_event_payment(&event_id, &sender, &receiver, &101, &None);
+    assert_eq!(result, Err(Ok(ContractError::SpendingLimitExceeded)));
+
+    client.event_payment(&event_id, &sender, &receiver, &100, &None);
+    client.event_payment(&event_id, &sender, &receiver, &100, &None);
+
+    // Third payment would exceed the per-event cap
+    let result = client.try_event_payment(&event_id, &sender, &receiver, &100, &None);
+    assert_eq!(result, Err(Ok(ContractError::SpendingLimitExceeded)));
+    client.event_payment(&event_id, &sender, &receiver, &50, &None);
+
+    let spending = client.get_wallet_spending(&event_id, &sender);
+    assert_eq!(spending.spent_total, 250);
+
+    // Failed payments are not recorded
+    assert_eq!(client.get_event(&event_id).total_volume, 250);
+}
+
+#[test]
+fn test_spending_limit_per_day_resets() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, sender, receiver) = setup_event_with_wallets(&env, &client);
+
+    client.set_self_spending_limit(&event_id, &sender, &SpendingLimit {
+        per_payment: None,
+        per_day: Some(300),
+        per_event: None,
+    });
+
+    client.event_payment(&event_id, &sender, &receiver, &200, &None);
+    let result = client.try_event_payment(&event_id, &sender, &receiver, &200, &None);
+    assert_eq!(result, Err(Ok(ContractError::SpendingLimitExceeded)));
+
+    // Next day the daily counter starts over
+    env.ledger().with_mut(|l| l.timestamp += 86400);
+    assert_eq!(client.get_wallet_spending(&event_id, &sender).spent_today, 0);
+    client.event_payment(&event_id, &sender, &receiver, &200, &None);
+
+    let spending = client.get_wallet_spending(&event_id, &sender);
+    assert_eq!(spending.spent_today, 200);
+    assert_eq!(spending.spent_total, 400);
+
+    // Receiver is not affected by the sender's self-set limit
+    client.event_payment(&event_id, &receiver, &sender, &350, &None);
+}
+
+#[test]
+fn test_spending_limit_tightest_wins() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, sender, _receiver) = setup_event_with_wallets(&env, &client);
+
+    client.set_event_spending_limit(&event_id, &SpendingLimit {
+        per_payment: Some(100),
+        per_day: Some(1000),
+        per_event: None,
+    });
+    // Wallet-specific organizer limit overrides the event default
+    client.set_wallet_spending_limit(&event_id, &sender, &SpendingLimit {
+        per_payment: Some(500),
+        per_day: None,
+        per_event: Some(2000),
+    });
+    // Self-set limit can only tighten
+    client.set_self_spending_limit(&event_id, &sender, &SpendingLimit {
+        per_payment: Some(800),
+        per_day: Some(600),
+        per_event: None,
+    });
+
+    let limit = client.get_spending_limit(&event_id, &sender);
+    assert_eq!(limit, SpendingLimit {
+        per_payment: Some(500),
+        per_day: Some(600),
+        per_event: Some(2000),
+    });
+
+    // Clearing the self-set limit falls back to the organizer limit
+    client.set_self_spending_limit(&event_id, &sender, &SpendingLimit::default());
+    assert_eq!(client.get_spending_limit(&event_id, &sender).per_day, None);
+
+    // Invalid caps are rejected
+    let result = client.try_set_self_spending_limit(&event_id, &sender, &SpendingLimit {
+        per_payment: Some(0),
+        per_day: None,
+        per_event: None,
+    });
+    assert_eq!(result, Err(Ok(ContractError::AmountMustBePositive)));
+}
+
+#[test]
+fn test_spending_limit_applies_to_ticket_sales() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, sender, receiver) = setup_event_with_wallets(&env, &client);
+
+    client.set_ticket_price(&event_id, &200);
+    client.set_event_spending_limit(&event_id, &SpendingLimit {
+        per_payment: None,
+        per_day: None,
+        per_event: Some(300),
+    });
+
+    // Primary sales count toward the buyer's caps
+    let ticket_id = client.purchase_ticket(&event_id, &sender, &None);
+    assert_eq!(client.get_wallet_spending(&event_id, &sender).spent_total, 200);
+    let result = client.try_purchase_ticket(&event_id, &sender, &None);
+    assert_eq!(result, Err(Ok(ContractError::SpendingLimitExceeded)));
+
+    // So do resales, for the wallet buying the ticket
+    client.list_ticket(&ticket_id, &200);
+    client.buy_ticket(&ticket_id, &receiver);
+    assert_eq!(client.get_wallet_spending(&event_id, &receiver).spent_total, 200);
+    let result = client.try_event_payment(&event_id, &receiver, &sender, &150, &None);
+    assert_eq!(result, Err(Ok(ContractError::SpendingLimitExceeded)));
+}
@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+// Split bills funded by several registered wallets
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, assert_last_event, MockTokenClient};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, String};
+
+#[test]
+fn test_split_bill_settles_when_fully_funded() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, alice, payee) = setup_event_with_wallets(&env, &client);
+    let bob = Address::generate(&env);
+    client.register_wallet_for_event(&event_id, &bob);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    let bill_id = client.open_bill(&event_id, &payee, &1000, &Some(String::from_str(&env, "Table 7")), &3600);
+    assert_eq!(client.get_bill(&bill_id).status, BillStatus::Open);
+
+    client.contribute_to_bill(&bill_id, &alice, &400);
+    client.contribute_to_bill(&bill_id, &bob, &300);
+    client.contribute_to_bill(&bill_id, &alice, &100);
+    assert_eq!(client.get_bill_contribution(&bill_id, &alice), 500);
+    assert_eq!(client.get_bill(&bill_id).contributors.len(), 2);
+
+    // Contributions can't overfund the bill
+    assert_eq!(
+        client.try_contribute_to_bill(&bill_id, &bob, &201),
+        Err(Ok(ContractError::ContributionExceedsRemaining))
+    );
+
+    client.contribute_to_bill(&bill_id, &bob, &200);
+    assert_last_event(&env, &client.address, &BillSettled { bill_id, event_id, payment_id: 1 });
+
+    // Settled in one transfer minus the 5% event fee
+    let bill = client.get_bill(&bill_id);
+    assert_eq!(bill.status, BillStatus::Settled);
+    assert_eq!(token.balance(&payee), 950);
+    assert_eq!(client.get_event_fees(&event_id), 50);
+    assert_eq!(client.get_event(&event_id).total_volume, 1000);
+
+    let payment = client.get_payment(&1);
+    assert_eq!(payment.to, payee);
+    assert_eq!(payment.amount, 1000);
+    assert_eq!(payment.memo, Some(String::from_str(&env, "Table 7")));
+
+    assert_eq!(client.try_contribute_to_bill(&bill_id, &alice, &1), Err(Ok(ContractError::InvalidState)));
+    assert_eq!(client.try_cancel_bill(&bill_id), Err(Ok(ContractError::InvalidState)));
+}
+
+#[test]
+fn test_split_bill_cancel_refunds_contributors() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, alice, payee) = setup_event_with_wallets(&env, &client);
+    let outsider = Address::generate(&env);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    let bill_id = client.open_bill(&event_id, &payee, &500, &None, &3600);
+    client.contribute_to_bill(&bill_id, &alice, &200);
+    assert_eq!(token.balance(&alice), 1000000 - 200);
+
+    // Only registered wallets can contribute
+    assert_eq!(
+        client.try_contribute_to_bill(&bill_id, &outsider, &100),
+        Err(Ok(ContractError::WalletNotRegistered))
+    );
+
+    assert_eq!(client.cancel_bill(&bill_id), 200);
+    assert_last_event(&env, &client.address, &BillCancelled { bill_id, event_id, refunded: 200 });
+    assert_eq!(token.balance(&alice), 1000000);
+    assert_eq!(client.get_bill(&bill_id).status, BillStatus::Cancelled);
+    assert_eq!(client.get_event_fees(&event_id), 0);
+
+    assert_eq!(client.try_get_bill(&99), Err(Ok(ContractError::RecordNotFound)));
+}
+
+#[test]
+fn test_contributors_reclaim_shares_after_the_deadline() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, alice, payee) = setup_event_with_wallets(&env, &client);
+    let bob = Address::generate(&env);
+    client.register_wallet_for_event(&event_id, &bob);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    assert_eq!(client.try_open_bill(&event_id, &payee, &500, &None, &1000), Err(Ok(ContractError::Expired)));
+    let bill_id = client.open_bill(&event_id, &payee, &500, &None, &4600);
+    client.contribute_to_bill(&bill_id, &alice, &200);
+    client.contribute_to_bill(&bill_id, &bob, &100);
+
+    // Shares stay locked until the deadline
+    assert_eq!(client.try_reclaim_bill_share(&bill_id, &alice), Err(Ok(ContractError::NotYetDue)));
+
+    // Past the deadline the bill takes no more money, and each contributor takes their share back
+    env.ledger().with_mut(|l| l.timestamp = 4600);
+    assert_eq!(client.try_contribute_to_bill(&bill_id, &bob, &200), Err(Ok(ContractError::Expired)));
+    assert_eq!(client.reclaim_bill_share(&bill_id, &alice), 200);
+    assert_last_event(&env, &client.address, &BillShareReclaimed { bill_id, event_id, contributor: alice.clone(), amount: 200 });
+    assert_eq!(token.balance(&alice), 1000000);
+    assert_eq!(client.get_bill_contribution(&bill_id, &alice), 0);
+    assert_eq!(client.get_bill(&bill_id).funded, 100);
+    assert_eq!(client.try_reclaim_bill_share(&bill_id, &alice), Err(Ok(ContractError::RecordNotFound)));
+
+    // The payee can still cancel, refunding whoever hasn't reclaimed yet
+    assert_eq!(client.cancel_bill(&bill_id), 100);
+    assert_eq!(token.balance(&bob), 1000000);
+    assert_eq!(client.try_reclaim_bill_share(&bill_id, &bob), Err(Ok(ContractError::InvalidState)));
+    assert_eq!(client.check_solvency().surplus, 0);
+}
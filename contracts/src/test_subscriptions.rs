@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+// Recurring subscriptions collected once per period
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, assert_last_event, MockTokenClient};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token::{StellarAssetClient, TokenClient}, Address, Env};
+
+#[test]
+fn test_subscription_collect_once_per_period() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, payer, club) = setup_event_with_wallets(&env, &client);
+    let token = MockTokenClient::new(&env, &token_address);
+    let week = 7 * 86400;
+
+    let subscription_id = client.create_subscription(&event_id, &payer, &club, &100, &week, &400);
+    assert_eq!(token.allowance(&payer, &client.address), 400);
+
+    // First payment is due immediately; anyone can trigger it
+    let payment_id = client.collect_due(&subscription_id);
+    assert_last_event(&env, &client.address, &SubscriptionCollected {
+        subscription_id,
+        event_id,
+        payment_id,
+        next_due: week,
+    });
+    assert_eq!(token.balance(&club), 95);
+    assert_eq!(client.get_event_fees(&event_id), 5);
+    assert_eq!(token.allowance(&payer, &client.address), 300);
+
+    // Second collection in the same period fails
+    assert_eq!(client.try_collect_due(&subscription_id), Err(Ok(ContractError::NotYetDue)));
+
+    env.ledger().with_mut(|l| l.timestamp = week);
+    client.collect_due(&subscription_id);
+    assert_eq!(client.try_collect_due(&subscription_id), Err(Ok(ContractError::NotYetDue)));
+
+    let subscription = client.get_subscription(&subscription_id);
+    assert_eq!(subscription.payments_collected, 2);
+    assert_eq!(subscription.next_due, 2 * week);
+    assert_eq!(token.balance(&club), 190);
+}
+
+#[test]
+fn test_subscription_cancel_and_allowance() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let (event_id, _organizer, payer, club) = setup_event_with_wallets(&env, &client);
+
+    assert_eq!(
+        client.try_create_subscription(&event_id, &payer, &club, &100, &0, &400),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_create_subscription(&event_id, &payer, &outsider, &100, &86400, &400),
+        Err(Ok(ContractError::WalletNotRegistered))
+    );
+
+    // Allowance only covers one payment
+    let subscription_id = client.create_subscription(&event_id, &payer, &club, &100, &86400, &100);
+    client.collect_due(&subscription_id);
+    env.ledger().with_mut(|l| l.timestamp = 86400);
+    assert_eq!(client.try_collect_due(&subscription_id), Err(Ok(ContractError::InsufficientAllowance)));
+
+    client.cancel_subscription(&subscription_id);
+    assert_last_event(&env, &client.address, &SubscriptionCancelled { subscription_id, event_id });
+    assert_eq!(client.try_collect_due(&subscription_id), Err(Ok(ContractError::InvalidState)));
+    assert_eq!(client.try_cancel_subscription(&subscription_id), Err(Ok(ContractError::InvalidState)));
+    assert_eq!(client.try_get_subscription(&99), Err(Ok(ContractError::RecordNotFound)));
+}
+
+#[test]
+fn test_subscription_allowance_expiry_is_relative_to_the_ledger() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.sequence_number = 5_000_000);
+
+    let client = EventPaymentContractClient::new(&env, &env.register(EventPaymentContract, ()));
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    client.initialize(&Address::generate(&env), &500, &token);
+    let (event_id, _organizer, payer, club) = setup_event_with_wallets(&env, &client);
+    StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    let token = TokenClient::new(&env, &token);
+
+    // A real token rejects approvals that expire before the current ledger
+    let subscription_id = client.create_subscription(&event_id, &payer, &club, &100, &86400, &200);
+    assert_eq!(token.allowance(&payer, &client.address), 200);
+    client.collect_due(&subscription_id);
+    assert_eq!(token.balance(&club), 95);
+    assert_eq!(token.allowance(&payer, &client.address), 100);
+}
@@ -0,0 +1,49 @@
+#![cfg(test)]
+
+// Tickets through the NFT-style interface
+use super::*;
+use crate::test_events::setup_test;
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+#[test]
+fn test_ticket_nft_interface() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let organizer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "NFT Show"), &None);
+
+    assert_eq!(client.name(), String::from_str(&env, "EventCoin Tickets"));
+    assert_eq!(client.symbol(), String::from_str(&env, "TICKET"));
+
+    let first = client.issue_ticket(&event_id, &alice, &100);
+    let second = client.issue_ticket(&event_id, &alice, &100);
+    assert_eq!(client.balance(&alice), 2);
+    assert_eq!(client.owner_of(&(first as u32)), alice);
+
+    // Metadata URI is the event base URI followed by the ticket id
+    client.set_ticket_base_uri(&event_id, &String::from_str(&env, "https://tickets.example/nft-show/"));
+    assert_eq!(client.token_uri(&(second as u32)), String::from_str(&env, "https://tickets.example/nft-show/2"));
+
+    // Generic NFT transfer moves ownership and balances
+    client.transfer(&alice, &bob, &(first as u32));
+    assert_eq!(client.owner_of(&(first as u32)), bob);
+    assert_eq!(client.balance(&alice), 1);
+    assert_eq!(client.balance(&bob), 1);
+    assert!(client.is_wallet_registered(&event_id, &bob));
+    assert_eq!(client.try_transfer(&alice, &bob, &(first as u32)), Err(Ok(ContractError::Unauthorized)));
+
+    // Resales keep balances in sync
+    client.list_ticket(&second, &100);
+    client.buy_ticket(&second, &bob);
+    assert_eq!(client.balance(&alice), 0);
+    assert_eq!(client.balance(&bob), 2);
+
+    // Transfers follow the event transferability rules
+    client.set_resale_config(&event_id, &ResaleConfig { transferable: false, max_markup_bps: 0, royalty_bps: 0 });
+    assert_eq!(client.try_transfer(&bob, &alice, &(first as u32)), Err(Ok(ContractError::TicketNotTransferable)));
+
+    assert_eq!(client.try_owner_of(&99), Err(Ok(ContractError::RecordNotFound)));
+    let long_uri = String::from_bytes(&env, &[b'a'; 201]);
+    assert_eq!(client.try_set_ticket_base_uri(&event_id, &long_uri), Err(Ok(ContractError::TextTooLong)));
+}
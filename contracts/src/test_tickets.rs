@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+// Tickets: issuance, transfers and the capped resale marketplace
+use super::*;
+use crate::test_events::{setup_test, assert_last_event, MockTokenClient};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+#[test]
+fn test_ticket_issue_and_transfer() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let organizer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Ticketed Show"), &None);
+
+    // Issuing a ticket registers the holder
+    let ticket_id = client.issue_ticket(&event_id, &alice, &100);
+    assert!(client.is_wallet_registered(&event_id, &alice));
+    assert_eq!(client.get_ticket(&ticket_id).owner, alice);
+    assert_eq!(
+        client.try_issue_ticket(&event_id, &organizer, &100),
+        Err(Ok(ContractError::OrganizerCannotRegister))
+    );
+
+    // Tickets move between wallets and the new holder is registered too
+    client.transfer_ticket(&ticket_id, &alice, &bob);
+    assert_last_event(&env, &client.address, &TicketTransferred {
+        ticket_id,
+        event_id,
+        from: alice.clone(),
+        to: bob.clone(),
+    });
+    assert_eq!(client.get_ticket(&ticket_id).owner, bob);
+    assert!(client.is_wallet_registered(&event_id, &bob));
+    assert_eq!(client.try_transfer_ticket(&ticket_id, &alice, &bob), Err(Ok(ContractError::Unauthorized)));
+
+    // Organizer can lock transfers
+    client.set_resale_config(&event_id, &ResaleConfig { transferable: false, max_markup_bps: 0, royalty_bps: 0 });
+    assert_eq!(client.try_transfer_ticket(&ticket_id, &bob, &alice), Err(Ok(ContractError::TicketNotTransferable)));
+    assert_eq!(client.try_list_ticket(&ticket_id, &100), Err(Ok(ContractError::TicketNotTransferable)));
+}
+
+#[test]
+fn test_ticket_resale_with_price_cap_and_royalty() {
+    let (env, client, _admin, token_address) = setup_test();
+    let organizer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token = MockTokenClient::new(&env, &token_address);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Resale Show"), &None);
+
+    // Resale up to 20% above face value, 5% royalty
+    client.set_resale_config(&event_id, &ResaleConfig { transferable: true, max_markup_bps: 2000, royalty_bps: 500 });
+    assert_eq!(
+        client.try_set_resale_config(&event_id, &ResaleConfig { transferable: true, max_markup_bps: 0, royalty_bps: 1001 }),
+        Err(Ok(ContractError::FeeRateExceeds10Percent))
+    );
+
+    let ticket_id = client.issue_ticket(&event_id, &seller, &100);
+    assert_eq!(client.try_list_ticket(&ticket_id, &121), Err(Ok(ContractError::ResalePriceAboveCap)));
+    assert_eq!(client.try_buy_ticket(&ticket_id, &buyer), Err(Ok(ContractError::InvalidState)));
+
+    client.list_ticket(&ticket_id, &120);
+    assert_eq!(client.get_ticket(&ticket_id).resale_price, Some(120));
+
+    // Listed tickets can't be transferred outside the marketplace
+    assert_eq!(client.try_transfer_ticket(&ticket_id, &seller, &buyer), Err(Ok(ContractError::InvalidState)));
+
+    client.buy_ticket(&ticket_id, &buyer);
+    assert_last_event(&env, &client.address, &TicketSold {
+        ticket_id,
+        event_id,
+        seller: seller.clone(),
+        buyer: buyer.clone(),
+        price: 120,
+        royalty: 6,
+    });
+
+    // Buyer owns the ticket, seller got price minus royalty, royalty is in event fees
+    let ticket = client.get_ticket(&ticket_id);
+    assert_eq!(ticket.owner, buyer);
+    assert_eq!(ticket.resale_price, None);
+    assert!(client.is_wallet_registered(&event_id, &buyer));
+    assert_eq!(token.balance(&buyer), 1000000 - 120);
+    assert_eq!(token.balance(&seller), 114);
+    assert_eq!(client.get_event_fees(&event_id), 6);
+    // Issued tickets aren't sales; the resale counts toward the event volume
+    assert_eq!(client.get_event(&event_id).total_volume, 120);
+
+    // Listing can be cancelled by the owner
+    client.list_ticket(&ticket_id, &110);
+    client.cancel_ticket_listing(&ticket_id);
+    assert_eq!(client.get_ticket(&ticket_id).resale_price, None);
+    assert_eq!(client.try_cancel_ticket_listing(&ticket_id), Err(Ok(ContractError::InvalidState)));
+}
+
+#[test]
+fn test_resale_markup_bounds_and_overflow() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let organizer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Overflow Show"), &None);
+
+    // Markup is capped at 100% of the face value
+    assert_eq!(
+        client.try_set_resale_config(&event_id, &ResaleConfig { transferable: true, max_markup_bps: 10001, royalty_bps: 0 }),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    client.set_resale_config(&event_id, &ResaleConfig { transferable: true, max_markup_bps: 10000, royalty_bps: 0 });
+
+    // A price cap that doesn't fit in i128 is an error, not a panic
+    let ticket_id = client.issue_ticket(&event_id, &seller, &(i128::MAX / 2 + 1));
+    assert_eq!(client.try_list_ticket(&ticket_id, &100), Err(Ok(ContractError::ArithmeticOverflow)));
+
+    let ticket_id = client.issue_ticket(&event_id, &seller, &100);
+    client.list_ticket(&ticket_id, &200);
+}
+
+#[test]
+fn test_listed_ticket_follows_later_resale_rules() {
+    let (env, client, _admin, _token_address) = setup_test();
+    let organizer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Locked Show"), &None);
+    client.set_resale_config(&event_id, &ResaleConfig { transferable: true, max_markup_bps: 2000, royalty_bps: 500 });
+
+    let ticket_id = client.issue_ticket(&event_id, &seller, &100);
+    client.list_ticket(&ticket_id, &110);
+
+    // The seller can't buy their own listing
+    assert_eq!(client.try_buy_ticket(&ticket_id, &seller), Err(Ok(ContractError::Unauthorized)));
+
+    // Turning transfers off also stops listings made before
+    client.set_resale_config(&event_id, &ResaleConfig { transferable: false, max_markup_bps: 2000, royalty_bps: 500 });
+    assert_eq!(client.try_buy_ticket(&ticket_id, &buyer), Err(Ok(ContractError::TicketNotTransferable)));
+    assert_eq!(client.get_ticket(&ticket_id).owner, seller);
+
+    client.set_resale_config(&event_id, &ResaleConfig { transferable: true, max_markup_bps: 2000, royalty_bps: 500 });
+    client.buy_ticket(&ticket_id, &buyer);
+    assert_eq!(client.get_ticket(&ticket_id).owner, buyer);
+}
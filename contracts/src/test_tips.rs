@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+// Artist tipping with its own fee rate and leaderboard
+use super::*;
+use crate::test_events::{setup_test, setup_event_with_wallets, assert_last_event, MockTokenClient};
+use soroban_sdk::{testutils::Address as _, Address};
+
+#[test]
+fn test_tips_with_own_fee_rate_and_leaderboard() {
+    let (env, client, _admin, token_address) = setup_test();
+    let (event_id, _organizer, fan, _receiver) = setup_event_with_wallets(&env, &client);
+    let dj = Address::generate(&env);
+    let band = Address::generate(&env);
+    let token = MockTokenClient::new(&env, &token_address);
+
+    // Only registered artists can be tipped
+    assert_eq!(client.try_tip(&event_id, &fan, &dj, &100), Err(Ok(ContractError::ArtistNotRegistered)));
+    client.register_artist(&event_id, &dj);
+    client.register_artist(&event_id, &band);
+    assert!(client.is_artist(&event_id, &dj));
+
+    // Tips are fee-free by default
+    let payment_id = client.tip(&event_id, &fan, &dj, &100);
+    assert_last_event(&env, &client.address, &TipSent {
+        payment_id,
+        event_id,
+        from: fan.clone(),
+        artist: dj.clone(),
+        amount: 100,
+        artist_total: 100,
+    });
+    assert_eq!(token.balance(&dj), 100);
+    assert_eq!(client.get_event_fees(&event_id), 0);
+
+    // Reduced tip fee, independent from the 5% event fee
+    client.set_tip_fee_rate(&event_id, &100);
+    client.tip(&event_id, &fan, &band, &300);
+    assert_eq!(token.balance(&band), 297);
+    assert_eq!(client.get_event_fees(&event_id), 3);
+    assert_eq!(client.get_payment(&2).fee_rate, 100);
+    assert_eq!(client.try_set_tip_fee_rate(&event_id, &1001), Err(Ok(ContractError::FeeRateExceeds10Percent)));
+
+    client.tip(&event_id, &fan, &dj, &50);
+    assert_eq!(client.get_artist_tips(&event_id, &dj), 150);
+
+    let leaderboard = client.get_tip_leaderboard(&event_id);
+    assert_eq!(leaderboard.len(), 2);
+    assert_eq!(leaderboard.get(0).unwrap(), ArtistTips { artist: band, total: 300 });
+    assert_eq!(leaderboard.get(1).unwrap(), ArtistTips { artist: dj, total: 150 });
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+// Organizer treasury: consolidated and partial fee withdrawals
+use super::*;
+use crate::test_events::{setup_test, assert_last_event, MockTokenClient};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+#[test]
+fn test_treasury_withdraws_fees_from_all_events() {
+    let (env, client, admin, token_address) = setup_test();
+    let token = MockTokenClient::new(&env, &token_address);
+    let organizer = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    let first = client.create_event(&organizer, &String::from_str(&env, "Day One"), &None);
+    let second = client.create_event(&organizer, &String::from_str(&env, "Day Two"), &None);
+    for event_id in [first, second] {
+        client.register_wallet_for_event(&event_id, &sender);
+        client.register_wallet_for_event(&event_id, &receiver);
+    }
+    assert_eq!(client.get_organizer_events(&organizer).len(), 2);
+
+    client.event_payment(&first, &sender, &receiver, &2000, &None);
+    client.event_payment(&second, &sender, &receiver, &1000, &None);
+
+    // Active events keep all their fees until a partial limit is configured
+    assert_eq!(client.withdraw_all_fees(&organizer), 0);
+    assert_eq!(client.try_set_active_withdrawal_limit(&admin, &10001), Err(Ok(ContractError::InvalidAmount)));
+    client.set_active_withdrawal_limit(&admin, &5000);
+
+    let treasury = client.get_treasury(&organizer);
+    assert_eq!(treasury.accumulated, 150);
+    assert_eq!(treasury.withdrawable, 75);
+
+    assert_eq!(client.withdraw_all_fees(&organizer), 75);
+    assert_last_event(&env, &client.address, &TreasuryWithdrawn {
+        organizer: organizer.clone(),
+        amount: 75,
+        event_count: 2,
+    });
+    assert_eq!(token.balance(&organizer), 75);
+    assert_eq!(client.get_event_fees(&first), 50);
+    assert_eq!(client.get_event_fees(&second), 25);
+
+    // The limit applies to everything accumulated, so repeated calls don't drain the event
+    assert_eq!(client.withdraw_all_fees(&organizer), 0);
+    client.event_payment(&second, &sender, &receiver, &1000, &None);
+    assert_eq!(client.withdraw_all_fees(&organizer), 25);
+
+    // Once deactivated, the remaining fees can be withdrawn in full
+    client.set_event_status(&first, &false);
+    assert_eq!(client.withdraw_all_fees(&organizer), 50);
+
+    let treasury = client.get_treasury(&organizer);
+    assert_eq!(treasury.accumulated, 50);
+    assert_eq!(treasury.withdrawable, 0);
+    assert_eq!(treasury.withdrawn, 150);
+    assert_eq!(token.balance(&organizer), 150);
+}
+
+#[test]
+fn test_withdrawable_fees_overflow_is_an_error() {
+    let (env, client, admin, _token_address) = setup_test();
+    let organizer = Address::generate(&env);
+    let event_id = client.create_event(&organizer, &String::from_str(&env, "Huge Night"), &None);
+    client.set_active_withdrawal_limit(&admin, &5000);
+
+    // Fees too large to scale by the limit are reported instead of panicking
+    env.as_contract(&client.address, || EventPaymentContract::add_event_fees(&env, event_id, i128::MAX / 2));
+    assert_eq!(client.try_get_treasury(&organizer), Err(Ok(ContractError::ArithmeticOverflow)));
+    assert_eq!(client.try_withdraw_all_fees(&organizer), Err(Ok(ContractError::ArithmeticOverflow)));
+
+    // Inactive events are withdrawn in full, without the limit
+    client.set_event_status(&event_id, &false);
+    assert_eq!(client.get_treasury(&organizer).withdrawable, i128::MAX / 2);
+}
@@ -27,7 +27,7 @@ interface PaymentFormProps {
 const METHOD_NAME = "event_payment";
 
 async function mapArgsForEventPayment(amount: number, fromAddress: string, vendorId?: string): Promise<any[]> {
-  // Para event_payment: event_id, from, to, amount
+  // Para event_payment: event_id, from, to, amount, memo (opcional)
   try {
     // Por enquanto, vamos usar um event_id fixo para teste
     const event_id = 1; // ID do evento de teste
@@ -40,7 +40,8 @@ async function mapArgsForEventPayment(amount: number, fromAddress: string, vendo
       amount
     });
     
-    return [event_id, fromAddress, toAddress, amount];
+    // memo = null → Option::None no contrato
+    return [event_id, fromAddress, toAddress, amount, null];
   } catch (error) {
     console.error('Error generating contract arguments:', error);
     throw new Error('Failed to prepare transaction data');